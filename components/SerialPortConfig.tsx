@@ -4,7 +4,7 @@ import { useState, useEffect } from 'react';
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card';
 import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
-import { SerialPortManager, PortInfo, isTauri } from '@/lib/tauri-serial';
+import { SerialPortManager, PortInfo, isTauri, isSerialError } from '@/lib/tauri-serial';
 import { Wifi, WifiOff, RefreshCw, Settings } from 'lucide-react';
 
 export default function SerialPortConfig() {
@@ -78,7 +78,7 @@ export default function SerialPortConfig() {
       
       setIsConnected(true);
     } catch (err) {
-      setError(err instanceof Error ? err.message : 'Failed to connect');
+      setError(isSerialError(err) ? err.message : 'Failed to connect');
       console.error(err);
     } finally {
       setLoading(false);
@@ -95,7 +95,7 @@ export default function SerialPortConfig() {
       await SerialPortManager.closePort(selectedPort);
       setIsConnected(false);
     } catch (err) {
-      setError(err instanceof Error ? err.message : 'Failed to disconnect');
+      setError(isSerialError(err) ? err.message : 'Failed to disconnect');
       console.error(err);
     } finally {
       setLoading(false);
@@ -7,8 +7,16 @@ import { Button } from '@/components/ui/button';
 import { Activity, Server, AlertCircle, CheckCircle, RefreshCw } from 'lucide-react';
 import { isTauri } from '@/lib/tauri-serial';
 
+interface ServerStatusInfo {
+  status: 'running' | 'starting' | 'stopped';
+  pid: number | null;
+  uptime_secs: number | null;
+  port: number | null;
+}
+
 export default function ServerStatus() {
-  const [status, setStatus] = useState<'running' | 'stopped' | 'unknown'>('unknown');
+  const [port, setPort] = useState<number | null>(null);
+  const [status, setStatus] = useState<'running' | 'starting' | 'stopped' | 'unknown'>('unknown');
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState('');
   const [isTauriEnv, setIsTauriEnv] = useState(false);
@@ -27,8 +35,9 @@ export default function ServerStatus() {
     if (!isTauri()) return;
     
     try {
-      const result = await invoke<string>('get_server_status');
-      setStatus(result as 'running' | 'stopped');
+      const result = await invoke<ServerStatusInfo>('get_server_status');
+      setStatus(result.status);
+      setPort(result.port);
       setError('');
     } catch (err) {
       console.error('Failed to check server status:', err);
@@ -89,6 +98,11 @@ export default function ServerStatus() {
                 <CheckCircle className="w-4 h-4 text-green-600" />
                 <span className="text-sm text-green-600 font-medium">Running</span>
               </>
+            ) : status === 'starting' ? (
+              <>
+                <Activity className="w-4 h-4 text-amber-500 animate-pulse" />
+                <span className="text-sm text-amber-600 font-medium">Starting...</span>
+              </>
             ) : status === 'stopped' ? (
               <>
                 <AlertCircle className="w-4 h-4 text-red-600" />
@@ -100,9 +114,11 @@ export default function ServerStatus() {
                 <span className="text-sm text-gray-600 font-medium">Checking...</span>
               </>
             )}
-            <span className="text-xs text-muted-foreground">
-              (http://localhost:5000)
-            </span>
+            {port !== null && (
+              <span className="text-xs text-muted-foreground">
+                (http://localhost:{port})
+              </span>
+            )}
           </div>
 
           <div className="flex gap-2">
@@ -115,7 +131,7 @@ export default function ServerStatus() {
               <RefreshCw className={`w-3 h-3 ${loading ? 'animate-spin' : ''}`} />
             </Button>
             
-            {status !== 'running' ? (
+            {status !== 'running' && status !== 'starting' ? (
               <Button
                 size="sm"
                 onClick={handleStart}
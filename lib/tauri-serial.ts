@@ -1,9 +1,14 @@
 import { invoke } from '@tauri-apps/api/core';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
 
 export interface PortInfo {
   name: string;
   port_type: string;
   description?: string;
+  vid?: number;
+  pid?: number;
+  serial_number?: string;
+  product?: string;
 }
 
 export interface SerialConfig {
@@ -11,15 +16,75 @@ export interface SerialConfig {
   data_bits: number;
   stop_bits: number;
   parity: 'none' | 'odd' | 'even';
+  exclusive?: boolean;
+  timeout_ms?: number;
+  flow_control?: 'none' | 'hardware' | 'software';
+}
+
+export interface DevicePreset {
+  name: string;
+  config: SerialConfig;
+}
+
+export type PortFilter = 'all' | 'usb' | 'bluetooth' | 'pci';
+
+export type TextEncoding = 'utf8' | 'ascii' | 'latin1';
+
+export type LineEnding = 'none' | 'cr' | 'lf' | 'crlf';
+
+export interface SerialDataEvent {
+  port_name: string;
+  data: number[];
+}
+
+export interface SerialBridgeDataEvent {
+  from: string;
+  to: string;
+  data: number[];
+}
+
+export interface SerialBridgeStoppedEvent {
+  port_a: string;
+  port_b: string;
+  reason: string;
+}
+
+export type SerialErrorCode =
+  | 'port_not_open'
+  | 'port_busy'
+  | 'disconnected'
+  | 'io_error'
+  | 'invalid_config'
+  | 'invalid_input'
+  | 'reserved'
+  | 'not_found'
+  | 'cancelled'
+  | 'other';
+
+/**
+ * Error returned by the serial commands
+ */
+export interface SerialError {
+  code: SerialErrorCode;
+  message: string;
+}
+
+export function isSerialError(error: unknown): error is SerialError {
+  return (
+    typeof error === 'object' &&
+    error !== null &&
+    'code' in error &&
+    'message' in error
+  );
 }
 
 export class SerialPortManager {
   /**
    * List all available serial ports
    */
-  static async listPorts(): Promise<PortInfo[]> {
+  static async listPorts(filter: PortFilter = 'all'): Promise<PortInfo[]> {
     try {
-      return await invoke<PortInfo[]>('list_serial_ports');
+      return await invoke<PortInfo[]>('list_serial_ports', { filter });
     } catch (error) {
       console.error('Failed to list serial ports:', error);
       throw error;
@@ -27,13 +92,31 @@ export class SerialPortManager {
   }
 
   /**
-   * Open a serial port with the specified configuration
+   * Settings for common device classes, for use as openPort presets
+   */
+  static async listDevicePresets(): Promise<DevicePreset[]> {
+    try {
+      return await invoke<DevicePreset[]>('list_device_presets');
+    } catch (error) {
+      console.error('Failed to list device presets:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Open a serial port with the specified configuration. With a preset,
+   * any field left out of the config is taken from it.
    */
-  static async openPort(portName: string, config: SerialConfig): Promise<string> {
+  static async openPort(
+    portName: string,
+    config: Partial<SerialConfig>,
+    preset?: string,
+  ): Promise<string> {
     try {
       return await invoke<string>('open_serial_port', {
         portName,
         config,
+        preset,
       });
     } catch (error) {
       console.error('Failed to open serial port:', error);
@@ -56,11 +139,45 @@ export class SerialPortManager {
   /**
    * Write data to a serial port
    */
-  static async writeData(portName: string, data: string): Promise<number> {
+  static async writeData(
+    portName: string,
+    data: string,
+    lineEnding: LineEnding = 'none',
+    flush: boolean = true,
+  ): Promise<number> {
     try {
       return await invoke<number>('write_serial_data', {
         portName,
         data,
+        lineEnding,
+        flush,
+      });
+    } catch (error) {
+      console.error('Failed to write to serial port:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Flush writes made with flush disabled
+   */
+  static async flush(portName: string): Promise<void> {
+    try {
+      await invoke('flush_serial_port', { portName });
+    } catch (error) {
+      console.error('Failed to flush serial port:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Write raw bytes to a serial port
+   */
+  static async writeBytes(portName: string, data: number[] | Uint8Array): Promise<number> {
+    try {
+      return await invoke<number>('write_serial_bytes', {
+        portName,
+        data: Array.from(data),
       });
     } catch (error) {
       console.error('Failed to write to serial port:', error);
@@ -71,11 +188,31 @@ export class SerialPortManager {
   /**
    * Read data from a serial port
    */
-  static async readData(portName: string, bufferSize: number = 1024): Promise<string> {
+  static async readData(
+    portName: string,
+    bufferSize: number = 1024,
+    encoding: TextEncoding = 'utf8',
+  ): Promise<string> {
     try {
       return await invoke<string>('read_serial_data', {
         portName,
         bufferSize,
+        encoding,
+      });
+    } catch (error) {
+      console.error('Failed to read from serial port:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Read raw bytes from a serial port
+   */
+  static async readBytes(portName: string, bufferSize: number = 1024): Promise<number[]> {
+    try {
+      return await invoke<number[]>('read_serial_bytes', {
+        portName,
+        bufferSize,
       });
     } catch (error) {
       console.error('Failed to read from serial port:', error);
@@ -83,6 +220,101 @@ export class SerialPortManager {
     }
   }
 
+  /**
+   * Start streaming a port's incoming data as serial-data events
+   */
+  static async startStream(portName: string): Promise<string> {
+    try {
+      return await invoke<string>('start_serial_stream', { portName });
+    } catch (error) {
+      console.error('Failed to start serial stream:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Stop streaming a port
+   */
+  static async stopStream(portName: string): Promise<string> {
+    try {
+      return await invoke<string>('stop_serial_stream', { portName });
+    } catch (error) {
+      console.error('Failed to stop serial stream:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Subscribe to data from every streaming port
+   */
+  static async onData(handler: (event: SerialDataEvent) => void): Promise<UnlistenFn> {
+    return listen<SerialDataEvent>('serial-data', (event) => handler(event.payload));
+  }
+
+  /**
+   * Forward everything each port receives to the other
+   */
+  static async startBridge(portA: string, portB: string, emitData: boolean = false): Promise<string> {
+    try {
+      return await invoke<string>('start_serial_bridge', { portA, portB, emitData });
+    } catch (error) {
+      console.error('Failed to start serial bridge:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Stop the bridge either port belongs to
+   */
+  static async stopBridge(portName: string): Promise<string> {
+    try {
+      return await invoke<string>('stop_serial_bridge', { portName });
+    } catch (error) {
+      console.error('Failed to stop serial bridge:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Subscribe to bytes forwarded by bridges started with emitData
+   */
+  static async onBridgeData(handler: (event: SerialBridgeDataEvent) => void): Promise<UnlistenFn> {
+    return listen<SerialBridgeDataEvent>('serial-bridge-data', (event) => handler(event.payload));
+  }
+
+  /**
+   * Subscribe to bridges stopping because one side closed or disconnected
+   */
+  static async onBridgeStopped(
+    handler: (event: SerialBridgeStoppedEvent) => void,
+  ): Promise<UnlistenFn> {
+    return listen<SerialBridgeStoppedEvent>('serial-bridge-stopped', (event) => handler(event.payload));
+  }
+
+  /**
+   * Emit serial-frame events for data framed by a start and end byte
+   */
+  static async setFraming(
+    portName: string,
+    startByte: number,
+    endByte: number,
+    maxLen: number,
+  ): Promise<void> {
+    try {
+      await invoke('set_framing', { portName, startByte, endByte, maxLen });
+    } catch (error) {
+      console.error('Failed to set serial framing:', error);
+      throw error;
+    }
+  }
+
+  /**
+   * Subscribe to complete frames from every streaming port with framing set
+   */
+  static async onFrame(handler: (event: SerialDataEvent) => void): Promise<UnlistenFn> {
+    return listen<SerialDataEvent>('serial-frame', (event) => handler(event.payload));
+  }
+
   /**
    * Get list of available baud rates
    */
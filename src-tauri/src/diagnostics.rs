@@ -0,0 +1,99 @@
+use serde::Serialize;
+use std::fs::File;
+use std::io::Write;
+use tauri::{AppHandle, Manager, State};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::serial::{self, SerialManager};
+use crate::server;
+
+// Enough of the backend's output to show what led up to a problem without
+// shipping the whole buffer
+const SERVER_LOG_TAIL_LINES: usize = 200;
+
+#[derive(Debug, Serialize)]
+struct AppInfo {
+    name: String,
+    version: String,
+    os: String,
+    arch: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ServerInfo {
+    status: server::ServerStatusInfo,
+    launch_command: Option<String>,
+}
+
+fn add_json<W, T>(zip: &mut ZipWriter<W>, name: &str, value: &T) -> Result<(), String>
+where
+    W: Write + std::io::Seek,
+    T: Serialize,
+{
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", name, e))?;
+    
+    zip.start_file(name, options)
+        .map_err(|e| format!("Failed to add {} to bundle: {}", name, e))?;
+    zip.write_all(json.as_bytes())
+        .map_err(|e| format!("Failed to write {} to bundle: {}", name, e))?;
+    
+    Ok(())
+}
+
+// Blocks on the server health probe and on every open port's lock
+fn write_diagnostics_bundle(app_handle: &AppHandle, file_path: &str) -> Result<(), String> {
+    let manager: State<SerialManager> = app_handle.state();
+    let package = app_handle.package_info();
+    let app_info = AppInfo {
+        name: package.name.clone(),
+        version: package.version.to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+    };
+    
+    // A machine without any serial devices (or without permission to list
+    // them) should still produce a bundle, so record the failure instead
+    let ports = serial::list_serial_ports(None).map_err(|e| format!("Unavailable: {}", e));
+    
+    let state: State<server::ServerState> = app_handle.state();
+    let server_info = ServerInfo {
+        status: server::server_status_internal(app_handle, server::DEFAULT_INSTANCE_ID),
+        launch_command: state
+            .instances
+            .lock()
+            .ok()
+            .and_then(|instances| {
+                instances
+                    .get(server::DEFAULT_INSTANCE_ID)
+                    .map(|instance| instance.launch_command.clone())
+            }),
+    };
+    
+    let server_logs = server::get_server_logs(app_handle.clone(), Some(SERVER_LOG_TAIL_LINES));
+    
+    let file = File::create(file_path)
+        .map_err(|e| format!("Failed to create bundle {}: {}", file_path, e))?;
+    let mut zip = ZipWriter::new(file);
+    
+    add_json(&mut zip, "app.json", &app_info)?;
+    add_json(&mut zip, "ports.json", &ports)?;
+    add_json(&mut zip, "open_ports.json", &manager.open_port_summaries()?)?;
+    add_json(&mut zip, "serial_errors.json", &manager.last_errors_snapshot()?)?;
+    add_json(&mut zip, "server.json", &server_info)?;
+    add_json(&mut zip, "server_logs.json", &server_logs)?;
+    
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize bundle {}: {}", file_path, e))?;
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn export_diagnostics_bundle(app_handle: AppHandle, file_path: String) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || write_diagnostics_bundle(&app_handle, &file_path))
+        .await
+        .map_err(|e| format!("Failed to export diagnostics bundle: {}", e))?
+}
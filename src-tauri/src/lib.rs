@@ -1,8 +1,14 @@
+mod diagnostics;
 mod serial;
+mod serial_error;
+mod serial_log;
 mod server;
+mod shutdown;
+mod xmodem;
 
 use serial::SerialManager;
 use server::ServerState;
+use tauri::RunEvent;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -18,13 +24,32 @@ pub fn run() {
         )?;
       }
       
-      // Auto-start backend server when app launches
+      // Auto-start backend server when app launches, unless turned off
+      let auto_start = server::load_auto_start_settings(app.handle());
+      if !auto_start.enabled {
+        println!("Backend server auto-start is disabled");
+        return Ok(());
+      }
+      
       let handle = app.handle().clone();
       tauri::async_runtime::spawn(async move {
         // Wait a bit for the app to initialize
-        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(auto_start.delay_ms)).await;
+        
+        // Starting waits for the server to become ready, so keep it off the
+        // async workers
+        let result = tauri::async_runtime::spawn_blocking(move || {
+          server::start_backend_server_internal(
+            handle,
+            server::DEFAULT_INSTANCE_ID,
+            &server::LaunchOptions::default(),
+          )
+        })
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|result| result);
         
-        match server::start_backend_server_internal(handle.clone()) {
+        match result {
           Ok(msg) => println!("✅ {}", msg),
           Err(e) => eprintln!("❌ Failed to auto-start server: {}", e),
         }
@@ -34,15 +59,108 @@ pub fn run() {
     })
     .invoke_handler(tauri::generate_handler![
       serial::list_serial_ports,
+      serial::list_serial_ports_sorted,
+      serial::start_port_watch,
+      serial::stop_port_watch,
+      serial::scan_for_devices,
+      serial::check_serial_access,
+      serial::list_device_presets,
       serial::open_serial_port,
+      serial::is_valid_port_name,
+      serial::describe_open_request,
       serial::close_serial_port,
+      serial::close_all_serial_ports,
+      serial::is_serial_port_open,
+      serial::list_open_serial_ports,
+      serial::grab_banner,
+      serial::open_and_probe,
       serial::write_serial_data,
+      serial::write_serial_bytes,
+      serial::write_serial_hex,
+      serial::write_serial_file,
+      serial::write_serial_paced,
+      serial::serial_xmodem_send,
+      serial::flush_serial_port,
       serial::read_serial_data,
+      serial::read_serial_bytes,
+      serial::read_serial_available,
+      serial::read_serial_line,
+      serial::read_serial_hexdump,
+      serial::read_serial_hex,
+      serial::read_serial_strict,
+      serial::read_at_least,
+      serial::read_serial_exact,
+      serial::cancel_serial_read,
+      serial::measure_jitter,
+      serial::stage_write,
+      serial::fire_staged_writes,
+      serial::start_serial_stream,
+      serial::stop_serial_stream,
+      serial::set_listener_poll_interval,
+      serial::start_serial_bridge,
+      serial::stop_serial_bridge,
+      serial::get_serial_config,
+      serial::set_serial_baud_rate,
+      serial::set_serial_timeout,
+      serial::set_dtr,
+      serial::set_rts,
+      serial::get_modem_status,
+      serial::send_break,
+      serial::bytes_available,
+      serial::clear_serial_buffer,
+      serial::set_read_buffer_size,
+      serial::set_buffer_watermarks,
+      serial::set_byte_map,
+      serial::set_framing,
+      serial::clear_framing,
+      serial::start_serial_logging,
+      serial::stop_serial_logging,
+      serial::start_serial_log,
+      serial::stop_serial_log,
+      serial::get_port_activity,
+      serial::get_serial_stats,
+      serial::get_all_serial_stats,
+      serial::reserve_port,
+      serial::release_port,
+      serial::get_last_serial_error,
       serial::get_available_baud_rates,
+      serial::validate_baud_rate,
+      serial::add_custom_baud_rate,
+      serial::remove_custom_baud_rate,
+      serial::serial_thread_count,
+      serial::reset_serial_subsystem,
+      serial::set_port_alias,
+      serial::list_port_aliases,
+      serial::remove_port_alias,
+      serial::clear_port_aliases,
+      serial::save_serial_profile,
+      serial::load_serial_profile,
       server::start_backend_server,
       server::stop_backend_server,
+      server::restart_backend_server,
       server::get_server_status,
+      server::get_server_url,
+      server::set_server_health_endpoint,
+      server::configure_server_endpoint,
+      server::load_server_log,
+      server::get_server_logs,
+      server::dump_server_logs,
+      server::configure_server_supervisor,
+      server::set_server_auto_restart,
+      server::check_node_version,
+      server::get_server_auto_start,
+      server::set_server_auto_start,
+      shutdown::shutdown_all,
+      diagnostics::export_diagnostics_bundle,
     ])
-    .run(tauri::generate_context!())
-    .expect("error while running tauri application");
+    .build(tauri::generate_context!())
+    .expect("error while building tauri application")
+    .run(|app_handle, event| {
+      if let RunEvent::Exit = event {
+        let report = shutdown::shutdown_all_internal(app_handle);
+        for error in &report.errors {
+          eprintln!("❌ Shutdown error: {}", error);
+        }
+      }
+    });
 }
@@ -1,92 +1,1028 @@
 use serde::{Deserialize, Serialize};
-use serialport::{SerialPort, SerialPortType};
-use std::collections::HashMap;
-use std::sync::Mutex;
-use std::time::Duration;
-use tauri::State;
+use serialport::{SerialPort, SerialPortBuilder, SerialPortType};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter, Manager, State};
+
+use crate::serial_error::SerialError;
+use crate::serial_log::{Direction, LogFormat, TrafficLog};
+use crate::xmodem::{self, XmodemError};
+
+// Past this many background serial threads we warn the frontend
+const SERIAL_THREAD_WARN_THRESHOLD: usize = 32;
+
+const LISTENER_BUFFER_SIZE: usize = 1024;
+const DEFAULT_POLL_INTERVAL_MS: u64 = 20;
+const DEFAULT_READ_TIMEOUT_MS: u64 = 100;
+const LISTENER_JOIN_TIMEOUT: Duration = Duration::from_secs(2);
+const DEFAULT_FILE_CHUNK_SIZE: usize = 4096;
+const DEFAULT_PORT_WATCH_INTERVAL_MS: u64 = 1000;
+const PORT_WATCH_STOP_POLL: Duration = Duration::from_millis(100);
+const SERIAL_PROFILES_FILE: &str = "serial-profiles.json";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PortInfo {
     pub name: String,
     pub port_type: String,
     pub description: Option<String>,
+    pub vid: Option<u16>,
+    pub pid: Option<u16>,
+    pub serial_number: Option<String>,
+    pub product: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SerialConfig {
     pub baud_rate: u32,
     pub data_bits: u8,
     pub stop_bits: u8,
     pub parity: String,
+    pub exclusive: Option<bool>,
+    pub timeout_ms: Option<u64>,
+    #[serde(default = "default_flow_control")]
+    pub flow_control: String,
+}
+
+fn default_flow_control() -> String {
+    "none".to_string()
+}
+
+// What open_serial_port accepts: a full SerialConfig, or a preset name with
+// only the fields that should differ from it
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SerialConfigOverrides {
+    pub baud_rate: Option<u32>,
+    pub data_bits: Option<u8>,
+    pub stop_bits: Option<u8>,
+    pub parity: Option<String>,
+    pub exclusive: Option<bool>,
+    pub timeout_ms: Option<u64>,
+    pub flow_control: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DevicePreset {
+    pub name: String,
+    pub config: SerialConfig,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerialErrorInfo {
+    pub message: String,
+    pub os_code: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortActivity {
+    pub last_read_ms: Option<u64>,
+    pub last_write_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialDataEvent {
+    port_name: String,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialBufferEvent {
+    port_name: String,
+    bytes_to_read: u32,
+    threshold: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialListenerErrorEvent {
+    port_name: String,
+    message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialPortEvent {
+    port_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialPortsChangedEvent {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialReadProgressEvent {
+    port_name: String,
+    bytes_read: usize,
+    total_bytes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialBridgeDataEvent {
+    from: String,
+    to: String,
+    data: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialBridgeStoppedEvent {
+    port_a: String,
+    port_b: String,
+    reason: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct SerialWriteProgressEvent {
+    port_name: String,
+    bytes_sent: u64,
+    total_bytes: u64,
+}
+
+// Background reader for one port. It owns a cloned handle so the blocking
+// read never happens while the port is locked.
+struct Listener {
+    stop: Arc<AtomicBool>,
+    poll_interval_ms: Arc<AtomicU64>,
+    stuck_threshold_ms: Option<u64>,
+    thread: JoinHandle<()>,
+    watchdog: Option<JoinHandle<()>>,
+}
+
+impl Listener {
+    // Must not be called with the port locked: the listener thread takes
+    // that lock after every read and joining would deadlock. A thread wedged
+    // inside the driver is detached after a grace period rather than hanging
+    // the caller along with it.
+    fn shutdown(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        
+        for thread in std::iter::once(self.thread).chain(self.watchdog) {
+            join_or_detach(thread, "listener");
+        }
+    }
+
+    // Tell a wedged listener to exit whenever its read finally returns,
+    // without waiting for that to happen
+    fn abandon(self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+fn join_or_detach(thread: JoinHandle<()>, what: &str) {
+    let deadline = Instant::now() + LISTENER_JOIN_TIMEOUT;
+    while !thread.is_finished() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+    
+    if thread.is_finished() {
+        let _ = thread.join();
+    } else {
+        log::warn!("Serial {} thread did not exit in time, detaching it", what);
+    }
+}
+
+// Forwards everything one port receives to the other, in both directions.
+// Each direction has its own thread reading a cloned handle, like a listener.
+struct Bridge {
+    port_a: String,
+    port_b: String,
+    stop: Arc<AtomicBool>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Bridge {
+    fn includes(&self, port_name: &str) -> bool {
+        self.port_a == port_name || self.port_b == port_name
+    }
+
+    // Same rule as Listener::shutdown: never call with either port locked
+    fn shutdown(self) {
+        self.stop.store(true, Ordering::SeqCst);
+        
+        for thread in self.threads {
+            join_or_detach(thread, "bridge");
+        }
+    }
+}
+
+// Polls the system port list for devices appearing and disappearing
+struct PortWatch {
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+// Input buffer levels the listener reports on. `high` fires on the way up and
+// `low` on the way down, and the gap between them keeps a level hovering
+// near one mark from flooding the frontend with events.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BufferWatermarks {
+    pub low: u32,
+    pub high: u32,
+}
+
+// Splits streamed bytes into frames delimited by `start` and `end`. Bytes
+// outside a frame are skipped, and a frame that grows past `max_len` is
+// dropped so decoding re-syncs on the next start byte.
+struct FrameDecoder {
+    start: u8,
+    end: u8,
+    max_len: usize,
+    frame: Option<Vec<u8>>,
+}
+
+impl FrameDecoder {
+    fn feed(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        let mut frames = Vec::new();
+        
+        for &byte in bytes {
+            if byte == self.start {
+                // A start byte inside a frame means the end was lost
+                self.frame = Some(Vec::new());
+                continue;
+            }
+            
+            let Some(frame) = self.frame.as_mut() else {
+                continue;
+            };
+            
+            if byte == self.end {
+                frames.extend(self.frame.take());
+            } else if frame.len() == self.max_len {
+                self.frame = None;
+            } else {
+                frame.push(byte);
+            }
+        }
+        
+        frames
+    }
+}
+
+// Snapshot of an open port for diagnostics, read back from the live handle
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenPortSummary {
+    pub name: String,
+    pub baud_rate: Option<u32>,
+    pub data_bits: Option<String>,
+    pub stop_bits: Option<String>,
+    pub parity: Option<String>,
+    pub flow_control: Option<String>,
+    pub stats: SerialStats,
+    pub last_read_ms: Option<u64>,
+    pub last_write_ms: Option<u64>,
+    pub listening: bool,
+    pub logging: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerialStats {
+    pub bytes_read: u64,
+    pub bytes_written: u64,
+    pub opened_at: u64,
+}
+
+// An open port plus the bookkeeping that lives and dies with it
+struct OpenPort {
+    port: Box<dyn SerialPort>,
+    config: SerialConfig,
+    opened_at: u64,
+    bytes_read: u64,
+    bytes_written: u64,
+    last_read_ms: Option<u64>,
+    last_write_ms: Option<u64>,
+    log: Option<TrafficLog>,
+    listener: Option<Listener>,
+    // Byte substitution tables, indexed by the original byte value
+    read_map: Option<[u8; 256]>,
+    write_map: Option<[u8; 256]>,
+    // Shared with the listener thread so it can be changed while it runs
+    watermarks: Arc<Mutex<Option<BufferWatermarks>>>,
+    // Bytes read by read_serial_line that don't yet form a complete line
+    line_buffer: Vec<u8>,
+    // Reused by every read so polling doesn't allocate a fresh buffer each
+    // call; only grows, up to max_read_size when that is set
+    read_buffer: Vec<u8>,
+    max_read_size: Option<usize>,
+    // When set, the listener also emits each complete frame as serial-frame
+    framing: Option<FrameDecoder>,
+    // Set when an I/O error shows the device is gone; the command that hit
+    // it removes the port once the port lock is released
+    disconnected: bool,
+}
+
+// Each port sits behind its own lock, so a blocking read on one port never
+// holds up commands on another. The ports map itself is only held for
+// lookups, inserts and removals, and never while an entry's lock is held.
+type SharedPort = Arc<Mutex<OpenPort>>;
+
+impl OpenPort {
+    fn new(port: Box<dyn SerialPort>, config: SerialConfig) -> Self {
+        OpenPort {
+            port,
+            config,
+            opened_at: now_millis(),
+            bytes_read: 0,
+            bytes_written: 0,
+            last_read_ms: None,
+            last_write_ms: None,
+            log: None,
+            listener: None,
+            read_map: None,
+            write_map: None,
+            watermarks: Arc::new(Mutex::new(None)),
+            line_buffer: Vec::new(),
+            read_buffer: Vec::new(),
+            max_read_size: None,
+            framing: None,
+            disconnected: false,
+        }
+    }
+
+    fn is_listening(&self) -> bool {
+        self.listener
+            .as_ref()
+            .is_some_and(|listener| !listener.thread.is_finished())
+    }
+
+    fn record_read(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        self.bytes_read += bytes.len() as u64;
+        self.last_read_ms = Some(now_millis());
+        self.log_traffic(Direction::Rx, bytes);
+    }
+
+    fn record_write(&mut self, bytes: &[u8]) {
+        self.bytes_written += bytes.len() as u64;
+        self.last_write_ms = Some(now_millis());
+        self.log_traffic(Direction::Tx, bytes);
+    }
+
+    // Traffic is logged and counted as it appears on the wire, so the maps
+    // are applied after record_read and before record_write
+    fn map_read(&self, bytes: &mut [u8]) {
+        apply_byte_map(self.read_map.as_ref(), bytes);
+    }
+
+    fn map_write(&self, bytes: &mut [u8]) {
+        apply_byte_map(self.write_map.as_ref(), bytes);
+    }
+
+    fn stats(&self) -> SerialStats {
+        SerialStats {
+            bytes_read: self.bytes_read,
+            bytes_written: self.bytes_written,
+            opened_at: self.opened_at,
+        }
+    }
+
+    // A failing log must never break the serial session, so the capture is
+    // dropped and reported instead
+    fn log_traffic(&mut self, direction: Direction, bytes: &[u8]) {
+        if let Some(log) = self.log.as_mut() {
+            if let Err(e) = log.write_chunk(direction, bytes) {
+                eprintln!("❌ Serial log write failed, logging stopped: {}", e);
+                self.log = None;
+            }
+        }
+    }
+
+    fn finish_log(&mut self) -> io::Result<()> {
+        match self.log.take() {
+            Some(log) => log.finish(),
+            None => Ok(()),
+        }
+    }
+}
+
+// A command that panics while holding one of the manager's locks poisons
+// it, and refusing every later call would leave serial access dead until
+// the app restarts. Each table stays structurally valid after a panic, so
+// the poison is ignored and the data used as-is.
+trait LockExt<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T>;
+}
+
+impl<T> LockExt<T> for Mutex<T> {
+    fn lock_or_recover(&self) -> MutexGuard<'_, T> {
+        self.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn clear_table<T: Default>(table: &Mutex<T>) {
+    *table.lock_or_recover() = T::default();
+    table.clear_poison();
+}
+
+fn apply_byte_map(map: Option<&[u8; 256]>, bytes: &mut [u8]) {
+    if let Some(map) = map {
+        for byte in bytes.iter_mut() {
+            *byte = map[*byte as usize];
+        }
+    }
+}
+
+// Errors that mean the device itself went away (e.g. a USB adapter was
+// unplugged), as opposed to a read that simply timed out
+fn is_disconnect(e: &io::Error) -> bool {
+    if matches!(
+        e.kind(),
+        io::ErrorKind::BrokenPipe | io::ErrorKind::NotConnected | io::ErrorKind::UnexpectedEof
+    ) {
+        return true;
+    }
+    
+    // EIO, ENXIO, ENODEV
+    #[cfg(unix)]
+    let codes: &[i32] = &[5, 6, 19];
+    // ERROR_GEN_FAILURE, ERROR_NO_SUCH_DEVICE, ERROR_DEVICE_NOT_CONNECTED
+    #[cfg(windows)]
+    let codes: &[i32] = &[31, 433, 1167];
+    #[cfg(not(any(unix, windows)))]
+    let codes: &[i32] = &[];
+    
+    e.raw_os_error().is_some_and(|code| codes.contains(&code))
+}
+
+// An I/O failure, reported as Disconnected when it shows the device is gone
+fn io_error(message: String, e: &io::Error) -> SerialError {
+    if is_disconnect(e) {
+        SerialError::Disconnected(message)
+    } else {
+        SerialError::IoError(message)
+    }
+}
+
+// Tears down a port whose device has gone away. The listener may be the
+// caller, so it is only told to stop rather than joined.
+fn release_disconnected_port(
+    app_handle: &AppHandle,
+    manager: &SerialManager,
+    port_name: &str,
+    entry: SharedPort,
+) {
+    let mut open = entry.lock_or_recover();
+    if let Some(listener) = open.listener.take() {
+        listener.abandon();
+    }
+    if let Err(e) = open.finish_log() {
+        eprintln!("❌ Failed to finalize log for {}: {}", port_name, e);
+    }
+    drop(open);
+    manager.staged_writes.lock_or_recover().remove(port_name);
+    
+    let _ = app_handle.emit(
+        "serial-disconnected",
+        SerialPortEvent {
+            port_name: port_name.to_string(),
+        },
+    );
+}
+
+// Passes a command's result through, removing the port first if the error
+// behind it was a disconnect
+fn check_disconnect<T>(
+    app_handle: &AppHandle,
+    manager: &SerialManager,
+    port_name: &str,
+    result: Result<T, SerialError>,
+) -> Result<T, SerialError> {
+    if result.is_err() {
+        let disconnected = manager
+            .port(port_name)
+            .ok()
+            .filter(|entry| entry.lock_or_recover().disconnected);
+        
+        if let Some(entry) = disconnected.and_then(|entry| manager.remove_port(port_name, &entry)) {
+            release_disconnected_port(app_handle, manager, port_name, entry);
+        }
+    }
+    
+    result
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
 }
 
 pub struct SerialManager {
-    ports: Mutex<HashMap<String, Box<dyn SerialPort>>>,
+    ports: Mutex<HashMap<String, SharedPort>>,
+    aliases: Mutex<HashMap<String, AliasTarget>>,
+    last_errors: Mutex<HashMap<String, SerialErrorInfo>>,
+    staged_writes: Mutex<HashMap<String, Vec<u8>>>,
+    thread_count: Arc<AtomicUsize>,
+    reservations: Mutex<HashMap<String, String>>,
+    custom_baud_rates: Mutex<BTreeSet<u32>>,
+    port_watch: Mutex<Option<PortWatch>>,
+    // Port names seen on the port watch's last poll
+    known_ports: Mutex<BTreeSet<String>>,
+    // Serializes the read-modify-write of the saved profiles file
+    profiles_lock: Mutex<()>,
+    // Ports whose in-progress multi-read loop should stop. Kept outside the
+    // port entries so cancelling never waits behind the read it cancels.
+    read_cancels: Mutex<BTreeSet<String>>,
+    bridges: Mutex<Vec<Bridge>>,
+    // Ports with an open in progress, claimed so the slow open itself can
+    // run without the map lock. Only ever taken inside the map lock.
+    opening: Mutex<BTreeSet<String>>,
+}
+
+// What an alias points at. Serial numbers follow a USB device across
+// reboots and replugs, where the OS port name may change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasTarget {
+    PortName(String),
+    SerialNumber(String),
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ThreadWarningEvent {
+    count: usize,
+    threshold: usize,
+}
+
+// Decrements the shared thread counter when a background thread exits,
+// including when it unwinds from a panic
+struct ThreadCountGuard(Arc<AtomicUsize>);
+
+impl Drop for ThreadCountGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 impl SerialManager {
     pub fn new() -> Self {
         SerialManager {
             ports: Mutex::new(HashMap::new()),
+            aliases: Mutex::new(HashMap::new()),
+            last_errors: Mutex::new(HashMap::new()),
+            staged_writes: Mutex::new(HashMap::new()),
+            thread_count: Arc::new(AtomicUsize::new(0)),
+            reservations: Mutex::new(HashMap::new()),
+            custom_baud_rates: Mutex::new(BTreeSet::new()),
+            port_watch: Mutex::new(None),
+            known_ports: Mutex::new(BTreeSet::new()),
+            profiles_lock: Mutex::new(()),
+            read_cancels: Mutex::new(BTreeSet::new()),
+            bridges: Mutex::new(Vec::new()),
+            opening: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    // Map an alias to its real port name; anything that isn't an alias is
+    // passed through unchanged so raw port names keep working.
+    fn resolve_port_name(&self, name: &str) -> Result<String, SerialError> {
+        let target = {
+            let aliases = self.aliases.lock_or_recover();
+            aliases.get(name).cloned()
+        };
+        
+        match target {
+            None => Ok(name.to_string()),
+            Some(AliasTarget::PortName(port_name)) => Ok(port_name),
+            Some(AliasTarget::SerialNumber(serial_number)) => {
+                let ports = serialport::available_ports().map_err(|e| SerialError::IoError(e.to_string()))?;
+                ports
+                    .into_iter()
+                    .find(|port| match &port.port_type {
+                        SerialPortType::UsbPort(info) => {
+                            info.serial_number.as_deref() == Some(serial_number.as_str())
+                        }
+                        _ => false,
+                    })
+                    .map(|port| port.port_name)
+                    .ok_or_else(|| {
+                        SerialError::NotFound(format!(
+                            "No connected port matches alias {} (serial number {})",
+                            name, serial_number
+                        ))
+                    })
+            }
+        }
+    }
+
+    fn port(&self, port_name: &str) -> Result<SharedPort, SerialError> {
+        self.ports
+            .lock_or_recover()
+            .get(port_name)
+            .cloned()
+            .ok_or_else(SerialError::port_not_open)
+    }
+
+    // Removes the port only if it is still `entry`, so a teardown racing a
+    // close and reopen can't take out the new port
+    fn remove_port(&self, port_name: &str, entry: &SharedPort) -> Option<SharedPort> {
+        let mut ports = self.ports.lock_or_recover();
+        if ports.get(port_name).is_some_and(|current| Arc::ptr_eq(current, entry)) {
+            ports.remove(port_name)
+        } else {
+            None
+        }
+    }
+
+    // Entries are cloned out so each can be locked without the map held
+    fn open_ports(&self) -> Vec<(String, SharedPort)> {
+        self.ports
+            .lock_or_recover()
+            .iter()
+            .map(|(name, entry)| (name.clone(), entry.clone()))
+            .collect()
+    }
+
+    // Checked between the reads of a long read loop. The cancel is consumed,
+    // so it stops only the loop that sees it.
+    fn check_read_cancelled(&self, port_name: &str) -> Result<(), SerialError> {
+        if self.read_cancels.lock_or_recover().remove(port_name) {
+            return Err(SerialError::Cancelled("Read cancelled".to_string()));
+        }
+        Ok(())
+    }
+
+    // Drops a cancel that arrived while no read was running, so it can't cut
+    // the next one short
+    fn begin_read(&self, port_name: &str) {
+        self.read_cancels.lock_or_recover().remove(port_name);
+    }
+
+    fn is_bridged(&self, port_name: &str) -> bool {
+        self.bridges
+            .lock_or_recover()
+            .iter()
+            .any(|bridge| bridge.includes(port_name))
+    }
+
+    // Joined with the bridges lock released, since a bridge thread that is
+    // ending takes it to remove itself
+    pub fn stop_bridges(&self) {
+        let bridges: Vec<Bridge> = self.bridges.lock_or_recover().drain(..).collect();
+        for bridge in bridges {
+            bridge.shutdown();
+        }
+    }
+
+    // A reserved port only accepts opens and writes from its owner
+    fn check_reservation(&self, port_name: &str, owner_id: Option<&str>) -> Result<(), SerialError> {
+        let reservations = self.reservations.lock_or_recover();
+        
+        match reservations.get(port_name) {
+            Some(owner) if Some(owner.as_str()) != owner_id => Err(SerialError::Reserved(format!(
+                "Reserved: port {} is reserved by {}",
+                port_name, owner
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    // Remember the failure (with the raw OS code, if any) for later triage and
+    // hand the error back so callers can return it directly.
+    fn record_error(&self, port_name: &str, error: SerialError, os_code: Option<i32>) -> SerialError {
+        self.last_errors.lock_or_recover().insert(
+            port_name.to_string(),
+            SerialErrorInfo {
+                message: error.message().to_string(),
+                os_code,
+            },
+        );
+        error
+    }
+
+    // Drain pending output and close every open port, returning the names
+    // that closed cleanly and the errors hit along the way
+    pub fn close_all(&self) -> Result<(Vec<String>, Vec<String>), SerialError> {
+        let drained: Vec<(String, SharedPort)> = {
+            let mut ports = self.ports.lock_or_recover();
+            ports.drain().collect()
+        };
+        let mut closed = Vec::new();
+        let mut errors = Vec::new();
+        
+        for (port_name, entry) in drained {
+            // The listener needs the port lock to finish, so it is joined
+            // with the lock released
+            let listener = entry.lock_or_recover().listener.take();
+            if let Some(listener) = listener {
+                listener.shutdown();
+            }
+            
+            let mut open = entry.lock_or_recover();
+            if let Err(e) = open.port.flush() {
+                errors.push(format!("Failed to flush {}: {}", port_name, e));
+            }
+            if let Err(e) = open.finish_log() {
+                errors.push(format!("Failed to finalize log for {}: {}", port_name, e));
+            }
+            closed.push(port_name);
+        }
+        
+        self.staged_writes.lock_or_recover().clear();
+        
+        Ok((closed, errors))
+    }
+
+    // Closes everything and empties every table. A poisoned lock is
+    // recovered rather than reported, since getting out of a bad state is
+    // the whole point of a reset.
+    pub fn reset(&self) -> Result<(), SerialError> {
+        self.ports.clear_poison();
+        // Before the ports close, so the bridges don't report them as gone
+        self.stop_bridges();
+        let (_, errors) = self.close_all()?;
+        self.stop_port_watch();
+        
+        clear_table(&self.aliases);
+        clear_table(&self.last_errors);
+        clear_table(&self.staged_writes);
+        clear_table(&self.reservations);
+        clear_table(&self.custom_baud_rates);
+        clear_table(&self.known_ports);
+        clear_table(&self.read_cancels);
+        
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SerialError::IoError(errors.join("; ")))
+        }
+    }
+
+    pub fn open_port_summaries(&self) -> Result<Vec<OpenPortSummary>, SerialError> {
+        Ok(self
+            .open_ports()
+            .into_iter()
+            .map(|(name, entry)| {
+                let open = entry.lock_or_recover();
+                OpenPortSummary {
+                    name,
+                    baud_rate: open.port.baud_rate().ok(),
+                    data_bits: open.port.data_bits().ok().map(|v| format!("{:?}", v)),
+                    stop_bits: open.port.stop_bits().ok().map(|v| format!("{:?}", v)),
+                    parity: open.port.parity().ok().map(|v| format!("{:?}", v)),
+                    flow_control: open.port.flow_control().ok().map(|v| format!("{:?}", v)),
+                    stats: open.stats(),
+                    last_read_ms: open.last_read_ms,
+                    last_write_ms: open.last_write_ms,
+                    listening: open.is_listening(),
+                    logging: open.log.is_some(),
+                }
+            })
+            .collect())
+    }
+
+    pub fn last_errors_snapshot(&self) -> Result<HashMap<String, SerialErrorInfo>, SerialError> {
+        let last_errors = self.last_errors.lock_or_recover();
+        Ok(last_errors.clone())
+    }
+
+    // Returns whether a watch was running
+    pub fn stop_port_watch(&self) -> bool {
+        let watch = self.port_watch.lock_or_recover().take();
+        
+        match watch {
+            Some(watch) => {
+                watch.stop.store(true, Ordering::SeqCst);
+                let _ = watch.thread.join();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn active_thread_count(&self) -> usize {
+        self.thread_count.load(Ordering::SeqCst)
+    }
+
+    // Every long-running serial thread (listeners, heartbeats, bridges) is
+    // started through here so it is counted while alive
+    fn spawn_tracked_thread<F>(
+        &self,
+        app_handle: &AppHandle,
+        name: String,
+        f: F,
+    ) -> Result<JoinHandle<()>, SerialError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let count = self.thread_count.fetch_add(1, Ordering::SeqCst) + 1;
+        let guard = ThreadCountGuard(self.thread_count.clone());
+        
+        if count == SERIAL_THREAD_WARN_THRESHOLD + 1 {
+            let _ = app_handle.emit(
+                "serial-thread-warning",
+                ThreadWarningEvent {
+                    count,
+                    threshold: SERIAL_THREAD_WARN_THRESHOLD,
+                },
+            );
+        }
+        
+        thread::Builder::new()
+            .name(name)
+            .spawn(move || {
+                let _guard = guard;
+                f();
+            })
+            .map_err(|e| SerialError::IoError(format!("Failed to spawn serial thread: {}", e)))
+    }
+}
+
+// Manufacturers of common USB-serial adapters and dev boards, matched
+// case-insensitively against the USB manufacturer and product strings
+const DEFAULT_PREFERRED_MANUFACTURERS: &[&str] = &["FTDI", "Silicon Labs", "Arduino"];
+
+fn port_info(port: &serialport::SerialPortInfo) -> PortInfo {
+    let port_type = match &port.port_type {
+        SerialPortType::UsbPort(_) => "USB".to_string(),
+        SerialPortType::BluetoothPort => "Bluetooth".to_string(),
+        SerialPortType::PciPort => "PCI".to_string(),
+        SerialPortType::Unknown => "Unknown".to_string(),
+    };
+    
+    let usb = match &port.port_type {
+        SerialPortType::UsbPort(info) => Some(info),
+        _ => None,
+    };
+    
+    PortInfo {
+        name: port.port_name.clone(),
+        port_type,
+        description: usb.map(|info| {
+            format!(
+                "USB Device - Manufacturer: {:?}",
+                info.manufacturer.as_ref().unwrap_or(&"Unknown".to_string())
+            )
+        }),
+        vid: usb.map(|info| info.vid),
+        pid: usb.map(|info| info.pid),
+        serial_number: usb.and_then(|info| info.serial_number.clone()),
+        product: usb.and_then(|info| info.product.clone()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PortFilter {
+    All,
+    Usb,
+    Bluetooth,
+    Pci,
+}
+
+impl PortFilter {
+    fn parse(filter: &str) -> Result<Self, SerialError> {
+        match filter {
+            "all" => Ok(PortFilter::All),
+            "usb" => Ok(PortFilter::Usb),
+            "bluetooth" => Ok(PortFilter::Bluetooth),
+            "pci" => Ok(PortFilter::Pci),
+            _ => Err(SerialError::InvalidInput(format!("Invalid port filter: {}", filter))),
+        }
+    }
+
+    fn matches(self, port_type: &SerialPortType) -> bool {
+        match self {
+            PortFilter::All => true,
+            PortFilter::Usb => matches!(port_type, SerialPortType::UsbPort(_)),
+            PortFilter::Bluetooth => matches!(port_type, SerialPortType::BluetoothPort),
+            PortFilter::Pci => matches!(port_type, SerialPortType::PciPort),
         }
     }
 }
 
 #[tauri::command]
-pub fn list_serial_ports() -> Result<Vec<PortInfo>, String> {
-    let ports = serialport::available_ports().map_err(|e| e.to_string())?;
+pub fn list_serial_ports(filter: Option<String>) -> Result<Vec<PortInfo>, SerialError> {
+    let filter = PortFilter::parse(filter.as_deref().unwrap_or("all"))?;
+    let ports = serialport::available_ports().map_err(|e| SerialError::IoError(e.to_string()))?;
     
     let port_infos: Vec<PortInfo> = ports
         .iter()
-        .map(|port| {
-            let port_type = match &port.port_type {
-                SerialPortType::UsbPort(_) => "USB".to_string(),
-                SerialPortType::BluetoothPort => "Bluetooth".to_string(),
-                SerialPortType::PciPort => "PCI".to_string(),
-                SerialPortType::Unknown => "Unknown".to_string(),
-            };
-            
-            PortInfo {
-                name: port.port_name.clone(),
-                port_type,
-                description: match &port.port_type {
-                    SerialPortType::UsbPort(info) => Some(format!(
-                        "USB Device - Manufacturer: {:?}",
-                        info.manufacturer.as_ref().unwrap_or(&"Unknown".to_string())
-                    )),
-                    _ => None,
-                },
-            }
-        })
+        .filter(|port| filter.matches(&port.port_type))
+        .map(port_info)
         .collect();
     
     Ok(port_infos)
 }
 
+// Lower is more relevant: preferred USB devices in list order, then other
+// USB devices, then everything else
+fn port_relevance(port: &serialport::SerialPortInfo, preferred: &[String]) -> usize {
+    match &port.port_type {
+        SerialPortType::UsbPort(info) => {
+            let identity = format!(
+                "{} {}",
+                info.manufacturer.as_deref().unwrap_or(""),
+                info.product.as_deref().unwrap_or("")
+            )
+            .to_lowercase();
+            
+            preferred
+                .iter()
+                .position(|name| identity.contains(&name.to_lowercase()))
+                .unwrap_or(preferred.len())
+        }
+        _ => preferred.len() + 1,
+    }
+}
+
 #[tauri::command]
-pub fn open_serial_port(
-    port_name: String,
-    config: SerialConfig,
-    manager: State<SerialManager>,
-) -> Result<String, String> {
-    let mut ports = manager.ports.lock().map_err(|e| e.to_string())?;
+pub fn list_serial_ports_sorted(
+    preferred_manufacturers: Option<Vec<String>>,
+) -> Result<Vec<PortInfo>, SerialError> {
+    let mut ports = serialport::available_ports().map_err(|e| SerialError::IoError(e.to_string()))?;
+    let preferred = preferred_manufacturers.unwrap_or_else(|| {
+        DEFAULT_PREFERRED_MANUFACTURERS
+            .iter()
+            .map(|name| name.to_string())
+            .collect()
+    });
+    
+    ports.sort_by(|a, b| {
+        port_relevance(a, &preferred)
+            .cmp(&port_relevance(b, &preferred))
+            .then_with(|| a.port_name.cmp(&b.port_name))
+    });
+    
+    Ok(ports.iter().map(port_info).collect())
+}
+
+// COM<n> on Windows (optionally with the \\.\ device prefix); /dev/tty* or
+// /dev/cu.* everywhere else
+fn port_name_matches_platform(name: &str) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        let name = name.strip_prefix(r"\\.\").unwrap_or(name);
+        match (name.get(..3), name.get(3..)) {
+            (Some(prefix), Some(number)) => {
+                prefix.eq_ignore_ascii_case("COM")
+                    && !number.is_empty()
+                    && number.chars().all(|c| c.is_ascii_digit())
+            }
+            _ => false,
+        }
+    }
+    
+    #[cfg(not(target_os = "windows"))]
+    {
+        name.strip_prefix("/dev/tty")
+            .or_else(|| name.strip_prefix("/dev/cu."))
+            .is_some_and(|rest| !rest.is_empty())
+    }
+}
+
+// Names outside the usual pattern are only rejected when they clearly can't be
+// a device; anything else (rfcomm, pts, custom udev symlinks) just gets a
+// warning so unusual-but-valid setups keep working
+fn check_port_name(name: &str) -> Result<(), SerialError> {
+    if port_name_matches_platform(name) {
+        return Ok(());
+    }
+    
+    let clearly_invalid = name.trim().is_empty()
+        || (cfg!(not(target_os = "windows")) && !std::path::Path::new(name).exists());
     
-    // Check if port is already open
-    if ports.contains_key(&port_name) {
-        return Err("Port is already open".to_string());
+    if clearly_invalid {
+        return Err(SerialError::InvalidInput(format!("\"{}\" doesn't look like a serial port name", name)));
     }
     
+    log::warn!("Port name {} doesn't match the usual pattern for this platform", name);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn is_valid_port_name(port_name: String) -> bool {
+    port_name_matches_platform(&port_name)
+}
+
+// Every setting is validated up front: a silently substituted default opens
+// a port that talks garbage to the device, which is far harder to debug
+fn build_port(port_name: &str, config: &SerialConfig) -> Result<SerialPortBuilder, SerialError> {
     let parity = match config.parity.as_str() {
         "none" => serialport::Parity::None,
         "odd" => serialport::Parity::Odd,
         "even" => serialport::Parity::Even,
-        _ => serialport::Parity::None,
+        // serialport only models none/odd/even. Opening without parity
+        // instead would silently corrupt every ninth-bit-addressed frame.
+        "mark" | "space" => {
+            return Err(SerialError::InvalidConfig(format!(
+                "Unsupported parity: {} parity is not available on this platform",
+                config.parity
+            )))
+        }
+        other => return Err(SerialError::InvalidConfig(format!("Invalid parity: {} (expected none, odd or even)", other))),
     };
     
     let stop_bits = match config.stop_bits {
         1 => serialport::StopBits::One,
         2 => serialport::StopBits::Two,
-        _ => serialport::StopBits::One,
+        // 1.5 is passed as 15; the driver's StopBits has no variant for it
+        15 => return Err(SerialError::InvalidConfig("Unsupported stop bits: 1.5 stop bits are not available".to_string())),
+        other => return Err(SerialError::InvalidConfig(format!("Unsupported stop bits: {} (expected 1 or 2)", other))),
     };
     
     let data_bits = match config.data_bits {
@@ -94,88 +1030,3318 @@ pub fn open_serial_port(
         6 => serialport::DataBits::Six,
         7 => serialport::DataBits::Seven,
         8 => serialport::DataBits::Eight,
-        _ => serialport::DataBits::Eight,
+        other => return Err(SerialError::InvalidConfig(format!("Unsupported data bits: {} (expected 5 to 8)", other))),
+    };
+    
+    let flow_control = match config.flow_control.as_str() {
+        "none" => serialport::FlowControl::None,
+        "hardware" => serialport::FlowControl::Hardware,
+        "software" => serialport::FlowControl::Software,
+        other => return Err(SerialError::InvalidConfig(format!("Invalid flow control: {}", other))),
     };
     
-    let port = serialport::new(&port_name, config.baud_rate)
-        .timeout(Duration::from_millis(100))
+    Ok(serialport::new(port_name, config.baud_rate)
+        .timeout(Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_READ_TIMEOUT_MS)))
         .data_bits(data_bits)
         .stop_bits(stop_bits)
         .parity(parity)
-        .open()
-        .map_err(|e| format!("Failed to open port: {}", e))?;
-    
-    ports.insert(port_name.clone(), port);
-    
-    Ok(format!("Port {} opened successfully", port_name))
+        .flow_control(flow_control))
 }
 
-#[tauri::command]
+// A zero timeout makes every read return immediately, turning read loops
+// into busy loops
+fn check_timeout(timeout_ms: Option<u64>) -> Result<(), SerialError> {
+    if timeout_ms == Some(0) {
+        return Err(SerialError::InvalidConfig("Read timeout must be at least 1 ms".to_string()));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn describe_open_request(config: SerialConfig) -> String {
+    let builder = match build_port("<port>", &config) {
+        Ok(builder) => builder,
+        Err(e) => return format!("error: {}", e),
+    };
+    
+    format!("{:#?}\nexclusive: {:?}", builder, config.exclusive)
+}
+
+fn open_error(manager: &SerialManager, port_name: &str, e: serialport::Error) -> SerialError {
+    // serialport::Error drops the raw code, so read it back from the
+    // thread's last OS error before anything else can overwrite it. Errnos
+    // serialport has no kind for, EBUSY among them, come through as Unknown.
+    let os_code = match e.kind() {
+        serialport::ErrorKind::Io(_)
+        | serialport::ErrorKind::NoDevice
+        | serialport::ErrorKind::Unknown => io::Error::last_os_error().raw_os_error(),
+        _ => None,
+    };
+    manager.record_error(port_name, classify_open_error(&e, os_code), os_code)
+}
+
+fn classify_open_error(e: &serialport::Error, os_code: Option<i32>) -> SerialError {
+    if is_port_busy(e, os_code) {
+        return SerialError::PortBusy("Port is in use by another application".to_string());
+    }
+    
+    let message = format!("Failed to open port: {}", e);
+    match e.kind() {
+        serialport::ErrorKind::NoDevice => SerialError::NotFound(message),
+        _ => SerialError::IoError(message),
+    }
+}
+
+// Windows reports a port held by another process as "Access is denied."
+// (ERROR_ACCESS_DENIED / ERROR_SHARING_VIOLATION), Unix as EBUSY once the
+// holder has claimed exclusive access
+#[cfg(windows)]
+fn is_port_busy(e: &serialport::Error, os_code: Option<i32>) -> bool {
+    matches!(e.kind(), serialport::ErrorKind::Io(io::ErrorKind::PermissionDenied))
+        || matches!(os_code, Some(5) | Some(32))
+}
+
+#[cfg(not(windows))]
+fn is_port_busy(_e: &serialport::Error, os_code: Option<i32>) -> bool {
+    os_code == Some(16)
+}
+
+// Exclusive access is a Unix tty concept (TIOCEXCL). serialport already claims
+// it on open there, so `Some(false)` is the only way to share the device with
+// other processes. Windows COM handles are always exclusive, so the flag has
+// nothing to change on that platform.
+#[cfg(unix)]
+fn open_port(
+    builder: SerialPortBuilder,
+    exclusive: Option<bool>,
+) -> serialport::Result<Box<dyn SerialPort>> {
+    let mut port = builder.open_native()?;
+    
+    if let Some(exclusive) = exclusive {
+        port.set_exclusive(exclusive)?;
+    }
+    
+    Ok(Box::new(port))
+}
+
+#[cfg(not(unix))]
+fn open_port(
+    builder: SerialPortBuilder,
+    _exclusive: Option<bool>,
+) -> serialport::Result<Box<dyn SerialPort>> {
+    builder.open()
+}
+
+fn open_port_entry(
+    manager: &SerialManager,
+    port_name: &str,
+    config: SerialConfig,
+    owner_id: Option<&str>,
+) -> Result<(), SerialError> {
+    check_port_name(port_name)?;
+    validate_baud_rate(config.baud_rate)?;
+    check_timeout(config.timeout_ms)?;
+    manager.check_reservation(port_name, owner_id)?;
+    let builder = build_port(port_name, &config)?;
+    
+    // Claim the name first so a second open of the same port fails fast,
+    // then open without the map lock: a slow driver must not hold up
+    // commands on every other port
+    {
+        let ports = manager.ports.lock_or_recover();
+        if ports.contains_key(port_name) {
+            return Err(SerialError::PortBusy("Port is already open".to_string()));
+        }
+        if !manager.opening.lock_or_recover().insert(port_name.to_string()) {
+            return Err(SerialError::PortBusy("Port is already being opened".to_string()));
+        }
+    }
+    
+    let opened = open_port(builder, config.exclusive).map_err(|e| open_error(manager, port_name, e));
+    
+    // The claim is dropped under the map lock, so the port is always either
+    // being opened or in the map until it is inserted
+    let mut ports = manager.ports.lock_or_recover();
+    manager.opening.lock_or_recover().remove(port_name);
+    ports.insert(port_name.to_string(), Arc::new(Mutex::new(OpenPort::new(opened?, config))));
+    drop(ports);
+    
+    manager.last_errors.lock_or_recover().remove(port_name);
+    
+    Ok(())
+}
+
+fn close_port_entry(manager: &SerialManager, port_name: &str) -> Result<(), SerialError> {
+    let removed = manager.ports.lock_or_recover().remove(port_name);
+    
+    let Some(entry) = removed else {
+        return Err(SerialError::PortNotOpen("Port not found or already closed".to_string()));
+    };
+    
+    // Joined with the port lock released, since the listener takes it
+    let listener = entry.lock_or_recover().listener.take();
+    if let Some(listener) = listener {
+        listener.shutdown();
+    }
+    if let Err(e) = entry.lock_or_recover().finish_log() {
+        eprintln!("❌ Failed to finalize log for {}: {}", port_name, e);
+    }
+    
+    // Anything staged for this port would otherwise fire on a later reopen
+    manager.staged_writes.lock_or_recover().remove(port_name);
+    
+    Ok(())
+}
+
+// Name, baud rate, data bits, stop bits, parity and flow control for device
+// classes whose settings new users tend not to know
+const DEVICE_PRESETS: &[(&str, u32, u8, u8, &str, &str)] = &[
+    ("GPS module (NMEA)", 9600, 8, 1, "none", "none"),
+    ("Arduino", 115200, 8, 1, "none", "none"),
+    ("ESP32 / ESP8266", 115200, 8, 1, "none", "none"),
+    ("Receipt printer", 115200, 8, 1, "none", "none"),
+    ("Bluetooth module (HC-05)", 9600, 8, 1, "none", "none"),
+    ("Barcode scanner", 9600, 8, 1, "none", "none"),
+    ("Modbus RTU", 9600, 8, 1, "even", "none"),
+    ("Network equipment console", 9600, 8, 1, "none", "none"),
+];
+
+fn device_presets() -> Vec<DevicePreset> {
+    DEVICE_PRESETS
+        .iter()
+        .map(|&(name, baud_rate, data_bits, stop_bits, parity, flow_control)| DevicePreset {
+            name: name.to_string(),
+            config: SerialConfig {
+                baud_rate,
+                data_bits,
+                stop_bits,
+                parity: parity.to_string(),
+                exclusive: None,
+                timeout_ms: None,
+                flow_control: flow_control.to_string(),
+            },
+        })
+        .collect()
+}
+
+#[tauri::command]
+pub fn list_device_presets() -> Vec<DevicePreset> {
+    device_presets()
+}
+
+// Without a preset every field except exclusive, timeout_ms and
+// flow_control has to be given, as with a plain SerialConfig
+fn resolve_config(preset: Option<&str>, config: SerialConfigOverrides) -> Result<SerialConfig, SerialError> {
+    let base = match preset {
+        Some(name) => Some(
+            device_presets()
+                .into_iter()
+                .find(|preset| preset.name.eq_ignore_ascii_case(name))
+                .ok_or_else(|| SerialError::NotFound(format!("Unknown device preset: {}", name)))?
+                .config,
+        ),
+        None => None,
+    };
+    
+    let missing = |field: &str| SerialError::InvalidConfig(format!("Missing {} (no preset given)", field));
+    
+    Ok(SerialConfig {
+        baud_rate: config.baud_rate.or(base.as_ref().map(|b| b.baud_rate)).ok_or_else(|| missing("baud_rate"))?,
+        data_bits: config.data_bits.or(base.as_ref().map(|b| b.data_bits)).ok_or_else(|| missing("data_bits"))?,
+        stop_bits: config.stop_bits.or(base.as_ref().map(|b| b.stop_bits)).ok_or_else(|| missing("stop_bits"))?,
+        parity: config.parity.or(base.as_ref().map(|b| b.parity.clone())).ok_or_else(|| missing("parity"))?,
+        exclusive: config.exclusive,
+        timeout_ms: config.timeout_ms,
+        flow_control: config
+            .flow_control
+            .or(base.map(|b| b.flow_control))
+            .unwrap_or_else(default_flow_control),
+    })
+}
+
+// Any rate validate_baud_rate accepts can be opened, not just the presets
+// from get_available_baud_rates; whether the adapter honours it is up to the
+// driver, which get_serial_config can confirm afterwards
+#[tauri::command]
+pub fn open_serial_port(
+    port_name: String,
+    config: Option<SerialConfigOverrides>,
+    preset: Option<String>,
+    owner_id: Option<String>,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let config = resolve_config(preset.as_deref(), config.unwrap_or_default())?;
+    let port_name = manager.resolve_port_name(&port_name)?;
+    open_port_entry(&manager, &port_name, config, owner_id.as_deref())?;
+    
+    Ok(format!("Port {} opened successfully", port_name))
+}
+
+#[tauri::command]
 pub fn close_serial_port(
     port_name: String,
     manager: State<SerialManager>,
-) -> Result<String, String> {
-    let mut ports = manager.ports.lock().map_err(|e| e.to_string())?;
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    close_port_entry(&manager, &port_name)?;
     
-    if ports.remove(&port_name).is_some() {
-        Ok(format!("Port {} closed successfully", port_name))
-    } else {
-        Err("Port not found or already closed".to_string())
+    Ok(format!("Port {} closed successfully", port_name))
+}
+
+// close_all releases each port's lock before stopping its stream, so a
+// reader that needs the lock can still finish and be joined
+#[tauri::command]
+pub fn close_all_serial_ports(manager: State<SerialManager>) -> Result<Vec<String>, SerialError> {
+    let (closed, errors) = manager.close_all()?;
+    
+    for error in errors {
+        eprintln!("❌ {}", error);
+    }
+    
+    Ok(closed)
+}
+
+// The backend's view is authoritative; the frontend's can drift after a
+// disconnect releases a port behind its back
+#[tauri::command]
+pub fn is_serial_port_open(port_name: String, manager: State<SerialManager>) -> bool {
+    // An alias whose device isn't connected can't have an open port
+    let Ok(port_name) = manager.resolve_port_name(&port_name) else {
+        return false;
+    };
+    manager.ports.lock_or_recover().contains_key(&port_name)
+}
+
+#[tauri::command]
+pub fn list_open_serial_ports(manager: State<SerialManager>) -> Vec<String> {
+    let mut names: Vec<String> = manager.ports.lock_or_recover().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+#[tauri::command]
+pub async fn grab_banner(
+    port_name: String,
+    config: SerialConfig,
+    wait_ms: u64,
+    keep_open: Option<bool>,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        
+        open_port_entry(&manager, &port_name, config, None)?;
+        
+        // Collect everything that arrives in the window rather than stopping
+        // at the first chunk, since banners often span several reads
+        let deadline = Instant::now() + Duration::from_millis(wait_ms);
+        let mut banner = Vec::new();
+        let mut result = Ok(());
+        
+        while Instant::now() < deadline {
+            if let Err(e) = read_port_bytes(&manager, &port_name, LISTENER_BUFFER_SIZE, &mut banner) {
+                result = Err(e);
+                break;
+            }
+        }
+        
+        if !keep_open.unwrap_or(false) || result.is_err() {
+            close_port_entry(&manager, &port_name)?;
+        }
+        result?;
+        
+        Ok(String::from_utf8_lossy(&banner).to_string())
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Banner grab failed: {}", e)))?
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenProbe {
+    pub responded: bool,
+    // Everything read before the match or the timeout
+    pub response: Vec<u8>,
+    // False when a failed probe rolled the open back
+    pub open: bool,
+}
+
+// Opens the port and checks something is actually listening on it: sends
+// `probe_bytes` and waits for `expected`, or for any reply when that is
+// omitted. An I/O error always closes the port again; a device that simply
+// didn't answer only does with `rollback_on_failure`.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn open_and_probe(
+    port_name: String,
+    config: SerialConfig,
+    probe_bytes: Vec<u8>,
+    expected: Option<Vec<u8>>,
+    timeout_ms: u64,
+    rollback_on_failure: Option<bool>,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<OpenProbe, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        
+        open_port_entry(&manager, &port_name, config, owner_id.as_deref())?;
+        
+        let expected = expected.filter(|expected| !expected.is_empty());
+        let matched = |response: &[u8]| match &expected {
+            Some(expected) => response.windows(expected.len()).any(|window| window == expected.as_slice()),
+            None => !response.is_empty(),
+        };
+        
+        let result = (|| -> Result<Vec<u8>, SerialError> {
+            {
+                let entry = manager.port(&port_name)?;
+                let mut open = entry.lock_or_recover();
+                write_open_port(&manager, &port_name, &mut open, probe_bytes, true)?;
+            }
+            
+            let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+            let mut response = Vec::new();
+            while !matched(&response) && Instant::now() < deadline {
+                read_port_bytes(&manager, &port_name, LISTENER_BUFFER_SIZE, &mut response)?;
+            }
+            Ok(response)
+        })();
+        
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                close_port_entry(&manager, &port_name)?;
+                return Err(e);
+            }
+        };
+        
+        let responded = matched(&response);
+        let rolled_back = !responded && rollback_on_failure.unwrap_or(false);
+        if rolled_back {
+            close_port_entry(&manager, &port_name)?;
+        }
+        
+        Ok(OpenProbe {
+            responded,
+            response,
+            open: !rolled_back,
+        })
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Probe failed: {}", e)))?
+}
+
+// A single write may take only part of the buffer, so keep going until all
+// of it is out. On failure the error comes back with how much was sent.
+fn write_fully<W: Write + ?Sized>(writer: &mut W, bytes: &[u8]) -> Result<(), (usize, io::Error)> {
+    let mut sent = 0;
+    
+    while sent < bytes.len() {
+        match writer.write(&bytes[sent..]) {
+            Ok(0) => {
+                return Err((
+                    sent,
+                    io::Error::new(io::ErrorKind::WriteZero, "port accepted no more bytes"),
+                ))
+            }
+            Ok(count) => sent += count,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err((sent, e)),
+        }
+    }
+    
+    Ok(())
+}
+
+fn write_open_port(
+    manager: &SerialManager,
+    port_name: &str,
+    open: &mut OpenPort,
+    mut bytes: Vec<u8>,
+    flush: bool,
+) -> Result<usize, SerialError> {
+    open.map_write(&mut bytes);
+    
+    if let Err((sent, e)) = write_fully(open.port.as_mut(), &bytes) {
+        if sent > 0 {
+            open.record_write(&bytes[..sent]);
+        }
+        open.disconnected |= is_disconnect(&e);
+        let message = format!(
+            "Failed to write to port after {} of {} bytes: {}",
+            sent,
+            bytes.len(),
+            e
+        );
+        return Err(manager.record_error(port_name, io_error(message, &e), e.raw_os_error()));
+    }
+    
+    // Counted before the flush: the driver has the bytes either way, so a
+    // failed flush mustn't drop them from the stats and the log
+    open.record_write(&bytes);
+    
+    if flush {
+        flush_open_port(manager, port_name, open)?;
+    }
+    
+    Ok(bytes.len())
+}
+
+fn flush_open_port(
+    manager: &SerialManager,
+    port_name: &str,
+    open: &mut OpenPort,
+) -> Result<(), SerialError> {
+    open.port.flush().map_err(|e| {
+        open.disconnected |= is_disconnect(&e);
+        manager.record_error(
+            port_name,
+            io_error(format!("Failed to flush port: {}", e), &e),
+            e.raw_os_error(),
+        )
+    })
+}
+
+// For the modem line and buffer calls, which fail with a serialport::Error.
+// The raw code is read back as in open_error so an unplugged device is
+// still caught by is_disconnect.
+fn control_error(
+    manager: &SerialManager,
+    port_name: &str,
+    open: &mut OpenPort,
+    context: &str,
+    e: serialport::Error,
+) -> SerialError {
+    let message = format!("{}: {}", context, e);
+    let os_code = match e.kind() {
+        serialport::ErrorKind::Io(_)
+        | serialport::ErrorKind::NoDevice
+        | serialport::ErrorKind::Unknown => io::Error::last_os_error().raw_os_error(),
+        _ => None,
+    };
+    let e = match os_code {
+        Some(code) => io::Error::from_raw_os_error(code),
+        None => io::Error::from(e),
+    };
+    
+    open.disconnected |= is_disconnect(&e);
+    manager.record_error(port_name, io_error(message, &e), os_code)
+}
+
+fn line_ending_bytes(line_ending: &str) -> Result<&'static [u8], SerialError> {
+    match line_ending {
+        "none" => Ok(b""),
+        "cr" => Ok(b"\r"),
+        "lf" => Ok(b"\n"),
+        "crlf" => Ok(b"\r\n"),
+        _ => Err(SerialError::InvalidInput(format!("Invalid line ending: {}", line_ending))),
     }
 }
 
+// The returned count includes the appended line ending. With `flush` set to
+// false the bytes may sit in the OS buffer until flush_serial_port or close,
+// which lets a burst of small writes share one flush at the end.
 #[tauri::command]
 pub fn write_serial_data(
     port_name: String,
     data: String,
+    owner_id: Option<String>,
+    line_ending: Option<String>,
+    flush: Option<bool>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<usize, SerialError> {
+    let mut bytes = data.into_bytes();
+    bytes.extend_from_slice(line_ending_bytes(line_ending.as_deref().unwrap_or("none"))?);
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    
+    let result = (|| -> Result<usize, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        write_open_port(&manager, &port_name, &mut open, bytes, flush.unwrap_or(true))
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[tauri::command]
+pub fn flush_serial_port(
+    port_name: String,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        flush_open_port(&manager, &port_name, &mut open)
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[tauri::command]
+pub fn write_serial_bytes(
+    port_name: String,
+    data: Vec<u8>,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<usize, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    
+    let result = (|| -> Result<usize, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        write_open_port(&manager, &port_name, &mut open, data, true)
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+// For protocol debugging, e.g. "1B 5B 41" or "0x1B 0x5B 0x41"
+#[tauri::command]
+pub fn write_serial_hex(
+    port_name: String,
+    hex: String,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
     manager: State<SerialManager>,
-) -> Result<usize, String> {
-    let mut ports = manager.ports.lock().map_err(|e| e.to_string())?;
+) -> Result<usize, SerialError> {
+    let data = parse_hex(&hex)?;
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    
+    let result = (|| -> Result<usize, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        write_open_port(&manager, &port_name, &mut open, data, true)
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+// Streams a file out in chunks without passing it through the frontend.
+// Each chunk is written and flushed under its own short lock, so other
+// commands can reach the port between chunks.
+#[tauri::command]
+pub async fn write_serial_file(
+    port_name: String,
+    file_path: String,
+    chunk_size: Option<usize>,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<u64, SerialError> {
+    let chunk_size = chunk_size.unwrap_or(DEFAULT_FILE_CHUNK_SIZE);
+    if chunk_size == 0 {
+        return Err(SerialError::InvalidInput("Chunk size must be non-zero".to_string()));
+    }
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    
+    let mut file = File::open(&file_path).map_err(|e| {
+        if e.kind() == io::ErrorKind::NotFound {
+            SerialError::NotFound(format!("File not found: {}", file_path))
+        } else {
+            SerialError::IoError(format!("Failed to open {}: {}", file_path, e))
+        }
+    })?;
+    let total_bytes = file
+        .metadata()
+        .map_err(|e| SerialError::IoError(format!("Failed to read {}: {}", file_path, e)))?
+        .len();
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        let mut chunk = vec![0u8; chunk_size];
+        let mut bytes_sent = 0u64;
+        
+        loop {
+            let count = file
+                .read(&mut chunk)
+                .map_err(|e| SerialError::IoError(format!("Failed to read {}: {}", file_path, e)))?;
+            if count == 0 {
+                break;
+            }
+            
+            let result = (|| -> Result<usize, SerialError> {
+                let entry = manager
+                    .port(&port_name)
+                    .map_err(|_| SerialError::PortNotOpen("Port was closed during the transfer".to_string()))?;
+                let mut open = entry.lock_or_recover();
+                write_open_port(&manager, &port_name, &mut open, chunk[..count].to_vec(), true)
+            })();
+            bytes_sent += check_disconnect(&app_handle, &manager, &port_name, result)? as u64;
+            
+            let _ = app_handle.emit(
+                "serial-write-progress",
+                SerialWriteProgressEvent {
+                    port_name: port_name.clone(),
+                    bytes_sent,
+                    total_bytes,
+                },
+            );
+        }
+        
+        Ok(bytes_sent)
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("File transfer failed: {}", e)))?
+}
+
+// For devices with tiny RX buffers that drop bytes at full line rate. Each
+// byte is flushed before the delay so the gap actually appears on the wire,
+// and the port lock is released while sleeping.
+#[tauri::command]
+pub async fn write_serial_paced(
+    port_name: String,
+    data: Vec<u8>,
+    delay_us: u64,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<usize, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    let delay = Duration::from_micros(delay_us);
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        let mut written = 0;
+        
+        for (i, &byte) in data.iter().enumerate() {
+            if i > 0 && !delay.is_zero() {
+                thread::sleep(delay);
+            }
+            
+            let result = (|| -> Result<usize, SerialError> {
+                let entry = manager
+                    .port(&port_name)
+                    .map_err(|_| SerialError::PortNotOpen("Port was closed during the write".to_string()))?;
+                let mut open = entry.lock_or_recover();
+                write_open_port(&manager, &port_name, &mut open, vec![byte], true)
+            })();
+            written += check_disconnect(&app_handle, &manager, &port_name, result)?;
+        }
+        
+        Ok(written)
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Paced write failed: {}", e)))?
+}
+
+// Hands an open port to a protocol driver while its traffic still reaches
+// the stats and any capture log. Byte maps are not applied.
+struct RecordingPort<'a>(&'a mut OpenPort);
+
+impl Read for RecordingPort<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let count = self.0.port.read(buf)?;
+        self.0.record_read(&buf[..count]);
+        Ok(count)
+    }
+}
+
+impl Write for RecordingPort<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let count = self.0.port.write(buf)?;
+        self.0.record_write(&buf[..count]);
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.port.flush()
+    }
+}
+
+// Uploads a file to a bootloader that speaks XMODEM. The port stays locked
+// for the whole transfer so nothing else can interleave with the protocol,
+// and serial-xmodem-progress is emitted as each block is acknowledged.
+// cancel_serial_read stops it between reads.
+#[tauri::command]
+pub async fn serial_xmodem_send(
+    port_name: String,
+    file_path: String,
+    owner_id: Option<String>,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<u64, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    
+    let data = std::fs::read(&file_path).map_err(|e| {
+        if e.kind() == io::ErrorKind::NotFound {
+            SerialError::NotFound(format!("File not found: {}", file_path))
+        } else {
+            SerialError::IoError(format!("Failed to read {}: {}", file_path, e))
+        }
+    })?;
+    let total_bytes = data.len() as u64;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        
+        let result = (|| -> Result<(), SerialError> {
+            let entry = manager.port(&port_name)?;
+            let mut open = entry.lock_or_recover();
+            
+            // The listener would swallow the receiver's ACKs
+            if open.is_listening() {
+                return Err(SerialError::PortBusy("Port is being read by a stream".to_string()));
+            }
+            // Stale input could be mistaken for the receiver's handshake
+            open.port
+                .clear(serialport::ClearBuffer::Input)
+                .map_err(|e| SerialError::IoError(format!("Failed to clear input buffer: {}", e)))?;
+            
+            let progress_handle = app_handle.clone();
+            let progress_port = port_name.clone();
+            manager.begin_read(&port_name);
+            xmodem::send(
+                &mut RecordingPort(&mut open),
+                &data,
+                |bytes_sent| {
+                    let _ = progress_handle.emit(
+                        "serial-xmodem-progress",
+                        SerialWriteProgressEvent {
+                            port_name: progress_port.clone(),
+                            bytes_sent: bytes_sent as u64,
+                            total_bytes,
+                        },
+                    );
+                },
+                || manager.check_read_cancelled(&port_name).is_err(),
+            )
+            .map_err(|e| match e {
+                XmodemError::Io(e) => {
+                    open.disconnected |= is_disconnect(&e);
+                    manager.record_error(
+                        &port_name,
+                        io_error(format!("XMODEM transfer failed: {}", e), &e),
+                        e.raw_os_error(),
+                    )
+                }
+                XmodemError::Cancelled | XmodemError::Aborted => SerialError::Cancelled(e.to_string()),
+                XmodemError::Protocol(message) => {
+                    manager.record_error(&port_name, SerialError::Other(message), None)
+                }
+            })
+        })();
+        check_disconnect(&app_handle, &manager, &port_name, result)?;
+        
+        Ok(total_bytes)
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("XMODEM transfer failed: {}", e)))?
+}
+
+// Single read from an open port, appended to `out`; returns how many bytes
+// arrived, 0 when the read timed out with no data available
+fn read_port_bytes(
+    manager: &SerialManager,
+    port_name: &str,
+    buffer_size: usize,
+    out: &mut Vec<u8>,
+) -> Result<usize, SerialError> {
+    let entry = manager.port(port_name)?;
+    let mut open = entry.lock_or_recover();
     
-    let port = ports
-        .get_mut(&port_name)
-        .ok_or_else(|| "Port not open".to_string())?;
+    read_open_port(manager, port_name, &mut open, buffer_size, out)
+}
+
+// The read lands in the port's own buffer and is copied once, straight into
+// the caller's `out`, so polling loops don't allocate per read
+fn read_open_port(
+    manager: &SerialManager,
+    port_name: &str,
+    open: &mut OpenPort,
+    buffer_size: usize,
+    out: &mut Vec<u8>,
+) -> Result<usize, SerialError> {
+    // Two readers on one port would each see only part of the stream
+    if open.is_listening() {
+        return Err(SerialError::PortBusy("Port is being read by a stream".to_string()));
+    }
     
-    let bytes = data.as_bytes();
-    let written = port
-        .write(bytes)
-        .map_err(|e| format!("Failed to write to port: {}", e))?;
+    let size = open.max_read_size.map_or(buffer_size, |max| buffer_size.min(max));
+    // Taken out of the port while in use so the stats and log can be updated
+    // from it; always put back so the allocation is kept
+    let mut buffer = std::mem::take(&mut open.read_buffer);
+    if buffer.len() < size {
+        buffer.resize(size, 0);
+    }
     
-    port.flush()
-        .map_err(|e| format!("Failed to flush port: {}", e))?;
+    let result = match open.port.read(&mut buffer[..size]) {
+        Ok(bytes_read) => {
+            let bytes = &mut buffer[..bytes_read];
+            open.record_read(bytes);
+            open.map_read(bytes);
+            out.extend_from_slice(bytes);
+            Ok(bytes_read)
+        }
+        Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {
+            Ok(0) // No data available
+        }
+        Err(e) => {
+            open.disconnected |= is_disconnect(&e);
+            Err(manager.record_error(
+                port_name,
+                io_error(format!("Failed to read from port: {}", e), &e),
+                e.raw_os_error(),
+            ))
+        }
+    };
+    open.read_buffer = buffer;
     
-    Ok(written)
+    result
+}
+
+// Latin-1 maps each byte straight to the code point of the same value. For
+// ASCII, bytes >= 0x80 are stripped unless `reject_non_ascii` is set.
+fn decode_text(bytes: &[u8], encoding: &str, reject_non_ascii: bool) -> Result<String, SerialError> {
+    match encoding {
+        "utf8" => Ok(String::from_utf8_lossy(bytes).to_string()),
+        "latin1" => Ok(bytes.iter().map(|&byte| byte as char).collect()),
+        "ascii" => {
+            if reject_non_ascii {
+                if let Some(offset) = bytes.iter().position(|byte| !byte.is_ascii()) {
+                    return Err(SerialError::InvalidInput(format!(
+                        "Non-ASCII byte 0x{:02x} at offset {}",
+                        bytes[offset], offset
+                    )));
+                }
+            }
+            Ok(bytes
+                .iter()
+                .filter(|byte| byte.is_ascii())
+                .map(|&byte| byte as char)
+                .collect())
+        }
+        _ => Err(SerialError::InvalidInput(format!("Unsupported encoding: {}", encoding))),
+    }
 }
 
 #[tauri::command]
 pub fn read_serial_data(
     port_name: String,
     buffer_size: usize,
+    encoding: Option<String>,
+    reject_non_ascii: Option<bool>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let encoding = encoding.unwrap_or_else(|| "utf8".to_string());
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut bytes = Vec::new();
+    let result = read_port_bytes(&manager, &port_name, buffer_size, &mut bytes);
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    decode_text(&bytes, &encoding, reject_non_ascii.unwrap_or(false))
+}
+
+// Exact bytes for binary protocols, where the lossy string conversion in
+// read_serial_data would corrupt anything that isn't valid UTF-8
+#[tauri::command]
+pub fn read_serial_bytes(
+    port_name: String,
+    buffer_size: usize,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<Vec<u8>, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut bytes = Vec::new();
+    let result = read_port_bytes(&manager, &port_name, buffer_size, &mut bytes);
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    Ok(bytes)
+}
+
+// Drains whatever is already buffered without waiting out the port timeout;
+// returns an empty vec when nothing is waiting
+#[tauri::command]
+pub fn read_serial_available(
+    port_name: String,
+    app_handle: AppHandle,
     manager: State<SerialManager>,
-) -> Result<String, String> {
-    let mut ports = manager.ports.lock().map_err(|e| e.to_string())?;
+) -> Result<Vec<u8>, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
     
-    let port = ports
-        .get_mut(&port_name)
-        .ok_or_else(|| "Port not open".to_string())?;
+    let result = (|| -> Result<Vec<u8>, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        
+        let available = open.port.bytes_to_read().map_err(|e| {
+            SerialError::IoError(format!("Failed to query bytes available: {}", e))
+        })?;
+        if available == 0 {
+            return Ok(Vec::new());
+        }
+        
+        let mut bytes = Vec::new();
+        read_open_port(&manager, &port_name, &mut open, available as usize, &mut bytes)?;
+        Ok(bytes)
+    })();
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+fn take_line(buffer: &mut Vec<u8>, delimiter: u8) -> Option<Vec<u8>> {
+    let end = buffer.iter().position(|&byte| byte == delimiter)?;
+    let mut line: Vec<u8> = buffer.drain(..=end).collect();
+    line.pop();
+    Some(line)
+}
+
+// Returns None when no complete line has arrived yet; partial data stays
+// buffered on the port for the next call
+#[tauri::command]
+pub fn read_serial_line(
+    port_name: String,
+    delimiter: u8,
+    max_len: usize,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<Option<String>, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
     
-    let mut buffer = vec![0u8; buffer_size];
+    let result = (|| -> Result<Option<Vec<u8>>, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        
+        // A previous read may already have buffered more than one line
+        let line = take_line_checked(&mut open.line_buffer, delimiter, max_len)?;
+        if line.is_some() {
+            return Ok(line);
+        }
+        
+        let mut line_buffer = std::mem::take(&mut open.line_buffer);
+        let result = read_open_port(&manager, &port_name, &mut open, LISTENER_BUFFER_SIZE, &mut line_buffer);
+        open.line_buffer = line_buffer;
+        result?;
+        take_line_checked(&mut open.line_buffer, delimiter, max_len)
+    })();
+    let line = check_disconnect(&app_handle, &manager, &port_name, result)?;
     
-    match port.read(&mut buffer) {
-        Ok(bytes_read) => {
-            let data = String::from_utf8_lossy(&buffer[..bytes_read]).to_string();
-            Ok(data)
+    Ok(line.map(|line| String::from_utf8_lossy(&line).to_string()))
+}
+
+// Discards the buffer when a line outgrows max_len, so a device that never
+// sends the delimiter can't grow it without bound
+fn take_line_checked(
+    buffer: &mut Vec<u8>,
+    delimiter: u8,
+    max_len: usize,
+) -> Result<Option<Vec<u8>>, SerialError> {
+    match take_line(buffer, delimiter) {
+        Some(line) if line.len() > max_len => {
+            Err(SerialError::Other(format!("Line exceeded maximum length of {} bytes", max_len)))
+        }
+        Some(line) => Ok(Some(line)),
+        None if buffer.len() > max_len => {
+            buffer.clear();
+            Err(SerialError::Other(format!("Line exceeded maximum length of {} bytes", max_len)))
         }
-        Err(ref e) if e.kind() == std::io::ErrorKind::TimedOut => {
-            Ok(String::new()) // No data available
+        None => Ok(None),
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrictRead {
+    pub text: String,
+    pub bytes_read: usize,
+    pub invalid_offset: Option<usize>,
+    pub invalid_bytes: Vec<u8>,
+    // The read ended mid-sequence, which may just be a character split
+    // across two reads rather than corruption
+    pub incomplete: bool,
+}
+
+fn decode_strict(bytes: &[u8]) -> StrictRead {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => StrictRead {
+            text: text.to_string(),
+            bytes_read: bytes.len(),
+            invalid_offset: None,
+            invalid_bytes: Vec::new(),
+            incomplete: false,
+        },
+        Err(e) => {
+            let offset = e.valid_up_to();
+            let invalid_end = match e.error_len() {
+                Some(len) => offset + len,
+                None => bytes.len(),
+            };
+            StrictRead {
+                // Everything before valid_up_to() is guaranteed valid UTF-8
+                text: String::from_utf8_lossy(&bytes[..offset]).to_string(),
+                bytes_read: bytes.len(),
+                invalid_offset: Some(offset),
+                invalid_bytes: bytes[offset..invalid_end].to_vec(),
+                incomplete: e.error_len().is_none(),
+            }
         }
-        Err(e) => Err(format!("Failed to read from port: {}", e)),
     }
 }
 
 #[tauri::command]
-pub fn get_available_baud_rates() -> Vec<u32> {
-    vec![
-        300, 1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200, 230400, 460800, 921600,
-    ]
+pub fn read_serial_strict(
+    port_name: String,
+    buffer_size: usize,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<StrictRead, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut bytes = Vec::new();
+    let result = read_port_bytes(&manager, &port_name, buffer_size, &mut bytes);
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    Ok(decode_strict(&bytes))
+}
+
+// Canonical `hexdump -C` layout: offset, 16 bytes split into two groups of
+// eight, ASCII gutter, and a trailing line with the total length
+fn format_hexdump(bytes: &[u8]) -> String {
+    let mut output = String::new();
+    
+    for (line, chunk) in bytes.chunks(16).enumerate() {
+        let mut hex = String::new();
+        for i in 0..16 {
+            if i == 8 {
+                hex.push(' ');
+            }
+            match chunk.get(i) {
+                Some(byte) => hex.push_str(&format!("{:02x} ", byte)),
+                None => hex.push_str("   "),
+            }
+        }
+        
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+            .collect();
+        
+        output.push_str(&format!("{:08x}  {} |{}|\n", line * 16, hex, ascii));
+    }
+    
+    if !bytes.is_empty() {
+        output.push_str(&format!("{:08x}\n", bytes.len()));
+    }
+    
+    output
+}
+
+#[tauri::command]
+pub fn read_serial_hexdump(
+    port_name: String,
+    buffer_size: usize,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut bytes = Vec::new();
+    let result = read_port_bytes(&manager, &port_name, buffer_size, &mut bytes);
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    Ok(format_hexdump(&bytes))
+}
+
+// Single-line form for terminal display, e.g. "AA BB CC"; empty on timeout
+#[tauri::command]
+pub fn read_serial_hex(
+    port_name: String,
+    buffer_size: usize,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut bytes = Vec::new();
+    let result = read_port_bytes(&manager, &port_name, buffer_size, &mut bytes);
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    Ok(bytes
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect::<Vec<_>>()
+        .join(" "))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+// The loop behind read_at_least and read_serial_exact. `read` is given how
+// many more bytes fit under `max_bytes` and appends what it gets; reading stops once `min_bytes` have
+// arrived, the deadline passes or cancel_serial_read is called, and
+// `on_progress` sees the running total after every read that got data.
+fn collect_reads<R, P>(
+    manager: &SerialManager,
+    port_name: &str,
+    min_bytes: usize,
+    max_bytes: usize,
+    deadline: Instant,
+    mut read: R,
+    mut on_progress: P,
+) -> Result<Vec<u8>, SerialError>
+where
+    R: FnMut(usize, &mut Vec<u8>) -> Result<usize, SerialError>,
+    P: FnMut(usize),
+{
+    let mut collected = Vec::with_capacity(max_bytes);
+    manager.begin_read(port_name);
+    
+    loop {
+        manager.check_read_cancelled(port_name)?;
+        if read(max_bytes - collected.len(), &mut collected)? > 0 {
+            on_progress(collected.len());
+        }
+        
+        if collected.len() >= min_bytes || Instant::now() >= deadline {
+            break;
+        }
+    }
+    
+    Ok(collected)
+}
+
+// Each read takes the port lock only for one port timeout, so other commands
+// on the port stay usable while this waits
+#[tauri::command]
+pub async fn read_at_least(
+    port_name: String,
+    min_bytes: usize,
+    max_bytes: usize,
+    timeout_ms: u64,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<String, SerialError> {
+    if max_bytes == 0 || min_bytes > max_bytes {
+        return Err(SerialError::InvalidInput("min_bytes must not exceed max_bytes, and max_bytes must be non-zero".to_string()));
+    }
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    
+    // Kept off the command thread so cancel_serial_read can run meanwhile
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        let collected = collect_reads(
+            &manager,
+            &port_name,
+            min_bytes,
+            max_bytes,
+            deadline,
+            |remaining, collected| {
+                let result = read_port_bytes(&manager, &port_name, remaining, collected);
+                check_disconnect(&app_handle, &manager, &port_name, result)
+            },
+            |_| {},
+        )?;
+        
+        Ok(to_hex(&collected))
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Read failed: {}", e)))?
+}
+
+// `complete` is false when the deadline passed first; `data` then holds
+// whatever did arrive
+#[derive(Debug, Clone, Serialize)]
+pub struct ExactRead {
+    pub data: Vec<u8>,
+    pub complete: bool,
+}
+
+// For fixed-length frames, where a single read may return only part of one.
+// Like read_at_least, the port lock is only held for one read at a time.
+#[tauri::command]
+pub async fn read_serial_exact(
+    port_name: String,
+    n: usize,
+    timeout_ms: u64,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<ExactRead, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        let data = collect_reads(
+            &manager,
+            &port_name,
+            n,
+            n,
+            deadline,
+            |remaining, collected| {
+                let result = read_port_bytes(&manager, &port_name, remaining, collected);
+                check_disconnect(&app_handle, &manager, &port_name, result)
+            },
+            |bytes_read| {
+                let _ = app_handle.emit(
+                    "serial-read-progress",
+                    SerialReadProgressEvent {
+                        port_name: port_name.clone(),
+                        bytes_read,
+                        total_bytes: n,
+                    },
+                );
+            },
+        )?;
+        
+        Ok(ExactRead {
+            complete: data.len() == n,
+            data,
+        })
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Read failed: {}", e)))?
+}
+
+// Stops a read_serial_exact, read_at_least or serial_xmodem_send waiting on
+// this port; it returns a Cancelled error after its current read call
+#[tauri::command]
+pub fn cancel_serial_read(port_name: String, manager: State<SerialManager>) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.port(&port_name)?;
+    
+    manager.read_cancels.lock_or_recover().insert(port_name);
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JitterStats {
+    pub chunks: usize,
+    pub mean_interval_ms: f64,
+    pub std_dev_ms: f64,
+    // Largest distance of any single interval from the mean
+    pub max_deviation_ms: f64,
+}
+
+#[tauri::command]
+pub async fn measure_jitter(
+    port_name: String,
+    duration_ms: u64,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<JitterStats, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        
+        let deadline = Instant::now() + Duration::from_millis(duration_ms);
+        let mut arrivals = Vec::new();
+        // Only arrival times matter, so one scratch buffer serves every read
+        let mut scratch = Vec::new();
+        
+        while Instant::now() < deadline {
+            scratch.clear();
+            let result = read_port_bytes(&manager, &port_name, LISTENER_BUFFER_SIZE, &mut scratch);
+            if check_disconnect(&app_handle, &manager, &port_name, result)? > 0 {
+                arrivals.push(Instant::now());
+            }
+        }
+        
+        if arrivals.len() < 2 {
+            return Err(SerialError::Other(format!(
+                "Not enough data to measure jitter: received {} chunk(s)",
+                arrivals.len()
+            )));
+        }
+        
+        let intervals: Vec<f64> = arrivals
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).as_secs_f64() * 1000.0)
+            .collect();
+        let count = intervals.len() as f64;
+        let mean = intervals.iter().sum::<f64>() / count;
+        let variance = intervals.iter().map(|interval| (interval - mean).powi(2)).sum::<f64>() / count;
+        let max_deviation = intervals
+            .iter()
+            .map(|interval| (interval - mean).abs())
+            .fold(0.0, f64::max);
+        
+        Ok(JitterStats {
+            chunks: arrivals.len(),
+            mean_interval_ms: mean,
+            std_dev_ms: variance.sqrt(),
+            max_deviation_ms: max_deviation,
+        })
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Jitter measurement failed: {}", e)))?
+}
+
+#[tauri::command]
+pub fn stage_write(
+    port_name: String,
+    data: String,
+    owner_id: Option<String>,
+    manager: State<SerialManager>,
+) -> Result<usize, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    manager.check_reservation(&port_name, owner_id.as_deref())?;
+    manager.port(&port_name)?;
+    
+    let mut staged = manager.staged_writes.lock_or_recover();
+    let buffer = staged.entry(port_name).or_default();
+    buffer.extend_from_slice(data.as_bytes());
+    
+    Ok(buffer.len())
+}
+
+#[tauri::command]
+pub fn fire_staged_writes(
+    port_names: Vec<String>,
+    owner_id: Option<String>,
+    manager: State<SerialManager>,
+) -> Result<Vec<usize>, SerialError> {
+    let port_names = port_names
+        .iter()
+        .map(|name| manager.resolve_port_name(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    
+    for port_name in &port_names {
+        manager.check_reservation(port_name, owner_id.as_deref())?;
+    }
+    
+    // Validate everything up front so a bad name doesn't leave half the
+    // devices triggered and the rest waiting
+    let mut entries = BTreeMap::new();
+    for port_name in &port_names {
+        let entry = manager
+            .port(port_name)
+            .map_err(|_| SerialError::PortNotOpen(format!("Port {} not open", port_name)))?;
+        entries.insert(port_name.as_str(), entry);
+    }
+    
+    // Every port is held for the whole burst. Taking the locks in name order
+    // keeps two overlapping bursts from deadlocking.
+    let mut ports: BTreeMap<&str, MutexGuard<'_, OpenPort>> = entries
+        .iter()
+        .map(|(name, entry)| (*name, entry.lock_or_recover()))
+        .collect();
+    let mut staged = manager.staged_writes.lock_or_recover();
+    
+    for port_name in &port_names {
+        if !staged.contains_key(port_name) {
+            return Err(SerialError::InvalidInput(format!("No data staged for {}", port_name)));
+        }
+    }
+    
+    let payloads: Vec<Vec<u8>> = port_names
+        .iter()
+        .map(|name| {
+            let mut payload = staged.remove(name).unwrap_or_default();
+            if let Some(open) = ports.get(name.as_str()) {
+                open.map_write(&mut payload);
+            }
+            payload
+        })
+        .collect();
+    drop(staged);
+    
+    // Push every payload out before flushing any port, keeping the gap
+    // between the first and last device as small as possible
+    for (port_name, payload) in port_names.iter().zip(&payloads) {
+        let open = ports.get_mut(port_name.as_str()).ok_or_else(SerialError::port_not_open)?;
+        open.port.write_all(payload).map_err(|e| {
+            manager.record_error(
+                port_name,
+                io_error(format!("Failed to write to {}: {}", port_name, e), &e),
+                e.raw_os_error(),
+            )
+        })?;
+    }
+    
+    for port_name in &port_names {
+        let open = ports.get_mut(port_name.as_str()).ok_or_else(SerialError::port_not_open)?;
+        open.port.flush().map_err(|e| {
+            manager.record_error(
+                port_name,
+                io_error(format!("Failed to flush {}: {}", port_name, e), &e),
+                e.raw_os_error(),
+            )
+        })?;
+    }
+    
+    for (port_name, payload) in port_names.iter().zip(&payloads) {
+        if let Some(open) = ports.get_mut(port_name.as_str()) {
+            open.record_write(payload);
+        }
+    }
+    
+    Ok(payloads.iter().map(|payload| payload.len()).collect())
+}
+
+fn available_port_names() -> Result<BTreeSet<String>, SerialError> {
+    let ports = serialport::available_ports().map_err(|e| SerialError::IoError(e.to_string()))?;
+    Ok(ports.into_iter().map(|port| port.port_name).collect())
+}
+
+fn run_port_watch(app_handle: AppHandle, stop: Arc<AtomicBool>, interval: Duration) {
+    let manager: State<SerialManager> = app_handle.state();
+    
+    while !stop.load(Ordering::SeqCst) {
+        // Sleep in short slices so stopping the watch doesn't wait a whole
+        // interval
+        let mut waited = Duration::ZERO;
+        while waited < interval && !stop.load(Ordering::SeqCst) {
+            let slice = PORT_WATCH_STOP_POLL.min(interval - waited);
+            thread::sleep(slice);
+            waited += slice;
+        }
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        
+        // Enumeration can fail transiently while a device is mid-plug, so
+        // just try again next time
+        let Ok(current) = available_port_names() else {
+            continue;
+        };
+        
+        let mut known = manager.known_ports.lock_or_recover();
+        if *known == current {
+            continue;
+        }
+        
+        let event = SerialPortsChangedEvent {
+            added: current.difference(&known).cloned().collect(),
+            removed: known.difference(&current).cloned().collect(),
+        };
+        *known = current;
+        drop(known);
+        
+        let _ = app_handle.emit("serial-ports-changed", event);
+    }
+}
+
+#[tauri::command]
+pub fn start_port_watch(
+    interval_ms: Option<u64>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let interval = Duration::from_millis(interval_ms.unwrap_or(DEFAULT_PORT_WATCH_INTERVAL_MS).max(100));
+    
+    // Restarting picks up the new interval
+    manager.stop_port_watch();
+    
+    // Changes are reported relative to the ports present right now
+    *manager.known_ports.lock_or_recover() = available_port_names()?;
+    
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread = {
+        let watch_handle = app_handle.clone();
+        let stop = stop.clone();
+        manager.spawn_tracked_thread(
+            &app_handle,
+            "serial-port-watch".to_string(),
+            move || run_port_watch(watch_handle, stop, interval),
+        )?
+    };
+    
+    *manager.port_watch.lock_or_recover() = Some(PortWatch { stop, thread });
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub fn stop_port_watch(manager: State<SerialManager>) -> bool {
+    manager.stop_port_watch()
+}
+
+// `entry` is the port this listener was started on. It is held weakly so a
+// listener that outlives its port (detached while wedged, say) neither keeps
+// the port open nor touches a later reopen under the same name.
+#[allow(clippy::too_many_arguments)]
+fn run_listener(
+    app_handle: AppHandle,
+    port_name: String,
+    entry: Weak<Mutex<OpenPort>>,
+    mut port: Box<dyn SerialPort>,
+    stop: Arc<AtomicBool>,
+    poll_interval_ms: Arc<AtomicU64>,
+    read_started_ms: Arc<AtomicU64>,
+    watermarks: Arc<Mutex<Option<BufferWatermarks>>>,
+) {
+    let mut buffer = vec![0u8; LISTENER_BUFFER_SIZE];
+    let mut above_high = false;
+    
+    while !stop.load(Ordering::SeqCst) {
+        let marks = *watermarks.lock_or_recover();
+        if let (Some(marks), Ok(bytes_to_read)) = (marks, port.bytes_to_read()) {
+            let crossed = if !above_high && bytes_to_read > marks.high {
+                Some(("serial-buffer-high", marks.high))
+            } else if above_high && bytes_to_read < marks.low {
+                Some(("serial-buffer-low", marks.low))
+            } else {
+                None
+            };
+            
+            if let Some((event, threshold)) = crossed {
+                above_high = !above_high;
+                let _ = app_handle.emit(
+                    event,
+                    SerialBufferEvent {
+                        port_name: port_name.clone(),
+                        bytes_to_read,
+                        threshold,
+                    },
+                );
+            }
+        }
+        
+        read_started_ms.store(now_millis(), Ordering::SeqCst);
+        let result = port.read(&mut buffer);
+        read_started_ms.store(0, Ordering::SeqCst);
+        
+        // The watchdog may have replaced this port while we were stuck
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        
+        match result {
+            Ok(bytes_read) if bytes_read > 0 => {
+                let mut data = buffer[..bytes_read].to_vec();
+                let mut frames = Vec::new();
+                
+                if let Some(entry) = entry.upgrade() {
+                    let mut open = entry.lock_or_recover();
+                    open.record_read(&data);
+                    open.map_read(&mut data);
+                    if let Some(decoder) = open.framing.as_mut() {
+                        frames = decoder.feed(&data);
+                    }
+                }
+                
+                let _ = app_handle.emit(
+                    "serial-data",
+                    SerialDataEvent {
+                        port_name: port_name.clone(),
+                        data,
+                    },
+                );
+                for frame in frames {
+                    let _ = app_handle.emit(
+                        "serial-frame",
+                        SerialDataEvent {
+                            port_name: port_name.clone(),
+                            data: frame,
+                        },
+                    );
+                }
+                
+                // Data is flowing, go straight back to reading
+                continue;
+            }
+            Ok(_) => {}
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => {
+                let manager: State<SerialManager> = app_handle.state();
+                let error = manager.record_error(
+                    &port_name,
+                    io_error(format!("Failed to read from port: {}", e), &e),
+                    e.raw_os_error(),
+                );
+                let _ = app_handle.emit(
+                    "serial-listener-error",
+                    SerialListenerErrorEvent {
+                        port_name: port_name.clone(),
+                        message: error.to_string(),
+                    },
+                );
+                
+                if is_disconnect(&e) {
+                    let removed = entry
+                        .upgrade()
+                        .and_then(|entry| manager.remove_port(&port_name, &entry));
+                    if let Some(entry) = removed {
+                        release_disconnected_port(&app_handle, &manager, &port_name, entry);
+                    }
+                }
+                break;
+            }
+        }
+        
+        // Idle: back off so a quiet device doesn't keep a core busy
+        let interval = poll_interval_ms.load(Ordering::SeqCst);
+        if interval > 0 {
+            thread::sleep(Duration::from_millis(interval));
+        }
+    }
+}
+
+// Watches a listener's in-flight read. A read that outlives the threshold
+// means the driver is wedged, so the port is reopened under a new listener.
+fn run_watchdog(
+    app_handle: AppHandle,
+    port_name: String,
+    stop: Arc<AtomicBool>,
+    read_started_ms: Arc<AtomicU64>,
+    threshold_ms: u64,
+) {
+    let check_interval = Duration::from_millis((threshold_ms / 4).clamp(10, 250));
+    
+    while !stop.load(Ordering::SeqCst) {
+        thread::sleep(check_interval);
+        
+        let started = read_started_ms.load(Ordering::SeqCst);
+        if started != 0
+            && now_millis().saturating_sub(started) > threshold_ms
+            && !stop.load(Ordering::SeqCst)
+        {
+            recover_stuck_port(&app_handle, &port_name);
+            return;
+        }
+    }
+}
+
+// Best effort: the stuck thread still holds its cloned handle until the
+// driver lets go, and on platforms where the device is exclusive that can
+// make the reopen fail, which is reported as serial-recovery-failed
+fn recover_stuck_port(app_handle: &AppHandle, port_name: &str) {
+    let manager: State<SerialManager> = app_handle.state();
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(port_name)?;
+        let mut open = entry.lock_or_recover();
+        
+        let (poll_interval_ms, stuck_threshold_ms) = match open.listener.take() {
+            Some(listener) => {
+                let settings = (
+                    listener.poll_interval_ms.load(Ordering::SeqCst),
+                    listener.stuck_threshold_ms,
+                );
+                listener.abandon();
+                settings
+            }
+            None => return Ok(()),
+        };
+        
+        let builder = build_port(port_name, &open.config)?;
+        open.port = open_port(builder, open.config.exclusive)
+            .map_err(|e| open_error(&manager, port_name, e))?;
+        
+        spawn_listener(
+            app_handle,
+            &manager,
+            port_name,
+            &entry,
+            &mut open,
+            poll_interval_ms,
+            stuck_threshold_ms,
+        )
+    })();
+    
+    match result {
+        Ok(()) => {
+            let _ = app_handle.emit(
+                "serial-recovered",
+                SerialPortEvent {
+                    port_name: port_name.to_string(),
+                },
+            );
+        }
+        Err(e) => {
+            let _ = app_handle.emit(
+                "serial-recovery-failed",
+                SerialListenerErrorEvent {
+                    port_name: port_name.to_string(),
+                    message: e.to_string(),
+                },
+            );
+        }
+    }
+}
+
+fn spawn_listener(
+    app_handle: &AppHandle,
+    manager: &SerialManager,
+    port_name: &str,
+    entry: &SharedPort,
+    open: &mut OpenPort,
+    poll_interval_ms: u64,
+    stuck_threshold_ms: Option<u64>,
+) -> Result<(), SerialError> {
+    let port = open
+        .port
+        .try_clone()
+        .map_err(|e| SerialError::IoError(format!("Failed to clone port handle: {}", e)))?;
+    let stop = Arc::new(AtomicBool::new(false));
+    let poll_interval_ms = Arc::new(AtomicU64::new(poll_interval_ms));
+    let read_started_ms = Arc::new(AtomicU64::new(0));
+    let watermarks = open.watermarks.clone();
+    let entry = Arc::downgrade(entry);
+    
+    let thread = {
+        let listener_handle = app_handle.clone();
+        let port_name = port_name.to_string();
+        let stop = stop.clone();
+        let poll_interval_ms = poll_interval_ms.clone();
+        let read_started_ms = read_started_ms.clone();
+        manager.spawn_tracked_thread(
+            app_handle,
+            format!("serial-listener-{}", port_name),
+            move || {
+                run_listener(
+                    listener_handle,
+                    port_name,
+                    entry,
+                    port,
+                    stop,
+                    poll_interval_ms,
+                    read_started_ms,
+                    watermarks,
+                )
+            },
+        )?
+    };
+    
+    let watchdog = match stuck_threshold_ms {
+        Some(threshold_ms) => {
+            let watchdog_handle = app_handle.clone();
+            let port_name = port_name.to_string();
+            let stop = stop.clone();
+            Some(manager.spawn_tracked_thread(
+                app_handle,
+                format!("serial-watchdog-{}", port_name),
+                move || {
+                    run_watchdog(watchdog_handle, port_name, stop, read_started_ms, threshold_ms)
+                },
+            )?)
+        }
+        None => None,
+    };
+    
+    open.listener = Some(Listener {
+        stop,
+        poll_interval_ms,
+        stuck_threshold_ms,
+        thread,
+        watchdog,
+    });
+    
+    Ok(())
+}
+
+// `stuck_threshold_ms` enables the stuck-read watchdog; it is off when omitted
+#[tauri::command]
+pub fn start_serial_stream(
+    port_name: String,
+    poll_interval_ms: Option<u64>,
+    stuck_threshold_ms: Option<u64>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    if open.is_listening() {
+        return Err(SerialError::PortBusy("Port is already streaming".to_string()));
+    }
+    if manager.is_bridged(&port_name) {
+        return Err(SerialError::PortBusy("Port is bridged to another port".to_string()));
+    }
+    
+    if let Some(threshold_ms) = stuck_threshold_ms {
+        let timeout_ms = open.port.timeout().as_millis() as u64;
+        if threshold_ms <= timeout_ms {
+            return Err(SerialError::InvalidConfig(format!(
+                "Watchdog threshold must be longer than the read timeout ({} ms)",
+                timeout_ms
+            )));
+        }
+    }
+    
+    spawn_listener(
+        &app_handle,
+        &manager,
+        &port_name,
+        &entry,
+        &mut open,
+        poll_interval_ms.unwrap_or(DEFAULT_POLL_INTERVAL_MS),
+        stuck_threshold_ms,
+    )?;
+    
+    Ok(format!("Streaming {}", port_name))
+}
+
+#[tauri::command]
+pub fn stop_serial_stream(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let listener = {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.listener.take()
+    };
+    
+    match listener {
+        Some(listener) => {
+            listener.shutdown();
+            Ok(format!("Stream stopped for {}", port_name))
+        }
+        None => Err(SerialError::Other("Port is not streaming".to_string())),
+    }
+}
+
+#[tauri::command]
+pub fn set_listener_poll_interval(
+    port_name: String,
+    poll_interval_ms: u64,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let open = entry.lock_or_recover();
+    
+    let listener = open
+        .listener
+        .as_ref()
+        .ok_or_else(|| SerialError::Other("Port is not streaming".to_string()))?;
+    
+    listener.poll_interval_ms.store(poll_interval_ms, Ordering::SeqCst);
+    
+    Ok(())
+}
+
+// Stops the bridge from one of its own threads and tells the frontend why.
+// Only the first thread to get here finds the bridge, so the event fires once.
+fn end_bridge(app_handle: &AppHandle, manager: &SerialManager, stop: &Arc<AtomicBool>, reason: String) {
+    stop.store(true, Ordering::SeqCst);
+    
+    let removed = {
+        let mut bridges = manager.bridges.lock_or_recover();
+        bridges
+            .iter()
+            .position(|bridge| Arc::ptr_eq(&bridge.stop, stop))
+            .map(|index| bridges.remove(index))
+    };
+    
+    if let Some(bridge) = removed {
+        let _ = app_handle.emit(
+            "serial-bridge-stopped",
+            SerialBridgeStoppedEvent {
+                port_a: bridge.port_a,
+                port_b: bridge.port_b,
+                reason,
+            },
+        );
+    }
+}
+
+// Closing either port from elsewhere ends the bridge, and the stop reason
+// should say which one went
+fn bridged_port(manager: &SerialManager, port_name: &str) -> Result<SharedPort, SerialError> {
+    manager
+        .port(port_name)
+        .map_err(|_| SerialError::PortNotOpen(format!("{} was closed", port_name)))
+}
+
+fn forward_bridge_data(
+    app_handle: &AppHandle,
+    manager: &SerialManager,
+    from: &str,
+    to: &str,
+    mut data: Vec<u8>,
+    emit_data: bool,
+) -> Result<(), SerialError> {
+    {
+        let entry = bridged_port(manager, from)?;
+        let mut open = entry.lock_or_recover();
+        open.record_read(&data);
+        open.map_read(&mut data);
+    }
+    
+    let result = (|| -> Result<usize, SerialError> {
+        let entry = bridged_port(manager, to)?;
+        let mut open = entry.lock_or_recover();
+        write_open_port(manager, to, &mut open, data.clone(), true)
+    })();
+    check_disconnect(app_handle, manager, to, result)?;
+    
+    if emit_data {
+        let _ = app_handle.emit(
+            "serial-bridge-data",
+            SerialBridgeDataEvent {
+                from: from.to_string(),
+                to: to.to_string(),
+                data,
+            },
+        );
+    }
+    
+    Ok(())
+}
+
+// `source` is the entry `port` was cloned from, held weakly as in
+// run_listener
+fn run_bridge(
+    app_handle: AppHandle,
+    from: String,
+    to: String,
+    source: Weak<Mutex<OpenPort>>,
+    mut port: Box<dyn SerialPort>,
+    stop: Arc<AtomicBool>,
+    emit_data: bool,
+) {
+    let mut buffer = vec![0u8; LISTENER_BUFFER_SIZE];
+    
+    while !stop.load(Ordering::SeqCst) {
+        let result = port.read(&mut buffer);
+        if stop.load(Ordering::SeqCst) {
+            break;
+        }
+        
+        let manager: State<SerialManager> = app_handle.state();
+        let outcome = match result {
+            Ok(bytes_read) if bytes_read > 0 => {
+                let data = buffer[..bytes_read].to_vec();
+                forward_bridge_data(&app_handle, &manager, &from, &to, data, emit_data)
+            }
+            Ok(_) => bridged_port(&manager, &from).and(bridged_port(&manager, &to)).map(|_| ()),
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {
+                bridged_port(&manager, &from).and(bridged_port(&manager, &to)).map(|_| ())
+            }
+            Err(e) => {
+                let error = manager.record_error(
+                    &from,
+                    io_error(format!("Failed to read from port: {}", e), &e),
+                    e.raw_os_error(),
+                );
+                if is_disconnect(&e) {
+                    let removed = source
+                        .upgrade()
+                        .and_then(|entry| manager.remove_port(&from, &entry));
+                    if let Some(entry) = removed {
+                        release_disconnected_port(&app_handle, &manager, &from, entry);
+                    }
+                }
+                Err(error)
+            }
+        };
+        
+        if let Err(e) = outcome {
+            end_bridge(&app_handle, &manager, &stop, e.to_string());
+            break;
+        }
+    }
+}
+
+// Ports stay usable for commands while bridged, but can't stream: the
+// listener and the bridge would each see only part of the incoming data.
+// `emit_data` reports everything forwarded as serial-bridge-data events.
+#[tauri::command]
+pub fn start_serial_bridge(
+    port_a: String,
+    port_b: String,
+    emit_data: Option<bool>,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_a = manager.resolve_port_name(&port_a)?;
+    let port_b = manager.resolve_port_name(&port_b)?;
+    if port_a == port_b {
+        return Err(SerialError::InvalidInput("Cannot bridge a port to itself".to_string()));
+    }
+    
+    let mut handles = Vec::new();
+    for port_name in [&port_a, &port_b] {
+        let entry = manager.port(port_name)?;
+        let open = entry.lock_or_recover();
+        if open.is_listening() {
+            return Err(SerialError::PortBusy(format!("{} is streaming; stop the stream first", port_name)));
+        }
+        let handle = open
+            .port
+            .try_clone()
+            .map_err(|e| SerialError::IoError(format!("Failed to clone port handle: {}", e)))?;
+        handles.push((Arc::downgrade(&entry), handle));
+    }
+    
+    let mut bridges = manager.bridges.lock_or_recover();
+    if let Some(bridge) = bridges.iter().find(|bridge| bridge.includes(&port_a) || bridge.includes(&port_b)) {
+        return Err(SerialError::PortBusy(format!(
+            "{} and {} are already bridged",
+            bridge.port_a, bridge.port_b
+        )));
+    }
+    
+    let stop = Arc::new(AtomicBool::new(false));
+    let emit_data = emit_data.unwrap_or(false);
+    let directions = [(&port_a, &port_b), (&port_b, &port_a)];
+    
+    let mut threads = Vec::new();
+    for ((from, to), (source, handle)) in directions.into_iter().zip(handles) {
+        let bridge_handle = app_handle.clone();
+        let (from, to) = (from.clone(), to.clone());
+        let stop_thread = stop.clone();
+        let spawned = manager.spawn_tracked_thread(
+            &app_handle,
+            format!("serial-bridge-{}", from),
+            move || run_bridge(bridge_handle, from, to, source, handle, stop_thread, emit_data),
+        );
+        match spawned {
+            Ok(thread) => threads.push(thread),
+            Err(e) => {
+                stop.store(true, Ordering::SeqCst);
+                return Err(e);
+            }
+        }
+    }
+    
+    bridges.push(Bridge {
+        port_a: port_a.clone(),
+        port_b: port_b.clone(),
+        stop,
+        threads,
+    });
+    
+    Ok(format!("Bridging {} and {}", port_a, port_b))
+}
+
+// Either port of the bridge can be named
+#[tauri::command]
+pub fn stop_serial_bridge(port_name: String, manager: State<SerialManager>) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let removed = {
+        let mut bridges = manager.bridges.lock_or_recover();
+        bridges
+            .iter()
+            .position(|bridge| bridge.includes(&port_name))
+            .map(|index| bridges.remove(index))
+    };
+    
+    match removed {
+        Some(bridge) => {
+            let message = format!("Bridge between {} and {} stopped", bridge.port_a, bridge.port_b);
+            bridge.shutdown();
+            Ok(message)
+        }
+        None => Err(SerialError::Other("Port is not bridged".to_string())),
+    }
+}
+
+// Reads the settings back from the driver, which may differ from what was
+// requested when an adapter coerces an unsupported value
+#[tauri::command]
+pub fn get_serial_config(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<SerialConfig, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let open = entry.lock_or_recover();
+    let port = &open.port;
+    let read_error = |e: serialport::Error| SerialError::IoError(format!("Failed to read port settings: {}", e));
+    
+    let data_bits = match port.data_bits().map_err(read_error)? {
+        serialport::DataBits::Five => 5,
+        serialport::DataBits::Six => 6,
+        serialport::DataBits::Seven => 7,
+        serialport::DataBits::Eight => 8,
+    };
+    let stop_bits = match port.stop_bits().map_err(read_error)? {
+        serialport::StopBits::One => 1,
+        serialport::StopBits::Two => 2,
+    };
+    let parity = match port.parity().map_err(read_error)? {
+        serialport::Parity::None => "none",
+        serialport::Parity::Odd => "odd",
+        serialport::Parity::Even => "even",
+    };
+    let flow_control = match port.flow_control().map_err(read_error)? {
+        serialport::FlowControl::None => "none",
+        serialport::FlowControl::Software => "software",
+        serialport::FlowControl::Hardware => "hardware",
+    };
+    
+    Ok(SerialConfig {
+        baud_rate: port.baud_rate().map_err(read_error)?,
+        data_bits,
+        stop_bits,
+        parity: parity.to_string(),
+        exclusive: open.config.exclusive,
+        timeout_ms: Some(port.timeout().as_millis() as u64),
+        flow_control: flow_control.to_string(),
+    })
+}
+
+#[tauri::command]
+pub fn set_serial_baud_rate(
+    port_name: String,
+    baud_rate: u32,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    validate_baud_rate(baud_rate)?;
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    open.port
+        .set_baud_rate(baud_rate)
+        .map_err(|e| SerialError::IoError(format!("Failed to set baud rate: {}", e)))?;
+    open.config.baud_rate = baud_rate;
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_serial_timeout(
+    port_name: String,
+    timeout_ms: u64,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    check_timeout(Some(timeout_ms))?;
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    // A read that legitimately waits out the timeout must not trip the watchdog
+    if let Some(threshold_ms) = open.listener.as_ref().and_then(|l| l.stuck_threshold_ms) {
+        if timeout_ms >= threshold_ms {
+            return Err(SerialError::InvalidConfig(format!(
+                "Read timeout must be shorter than the watchdog threshold ({} ms)",
+                threshold_ms
+            )));
+        }
+    }
+    
+    open.port
+        .set_timeout(Duration::from_millis(timeout_ms))
+        .map_err(|e| SerialError::IoError(format!("Failed to set timeout: {}", e)))?;
+    // Keep the stored config in step so a watchdog reopen uses the new value.
+    // A running stream reads through its own cloned handle and keeps the old
+    // timeout until it is restarted.
+    open.config.timeout_ms = Some(timeout_ms);
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_dtr(
+    port_name: String,
+    level: bool,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.port
+            .write_data_terminal_ready(level)
+            .map_err(|e| control_error(&manager, &port_name, &mut open, "Failed to set DTR", e))
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[tauri::command]
+pub fn set_rts(
+    port_name: String,
+    level: bool,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.port
+            .write_request_to_send(level)
+            .map_err(|e| control_error(&manager, &port_name, &mut open, "Failed to set RTS", e))
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModemStatus {
+    pub cts: bool,
+    pub dsr: bool,
+    pub carrier_detect: bool,
+    pub ring_indicator: bool,
+}
+
+fn read_modem_status(port: &mut dyn SerialPort) -> serialport::Result<ModemStatus> {
+    Ok(ModemStatus {
+        cts: port.read_clear_to_send()?,
+        dsr: port.read_data_set_ready()?,
+        carrier_detect: port.read_carrier_detect()?,
+        ring_indicator: port.read_ring_indicator()?,
+    })
+}
+
+#[tauri::command]
+pub fn get_modem_status(
+    port_name: String,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<ModemStatus, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<ModemStatus, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        read_modem_status(open.port.as_mut()).map_err(|e| {
+            control_error(&manager, &port_name, &mut open, "Failed to read modem status", e)
+        })
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+// Async so the break can be held without tying up a runtime thread; the
+// port lock is only taken to set and clear it, not across the wait
+#[tauri::command]
+pub async fn send_break(
+    port_name: String,
+    duration_ms: u64,
+    app_handle: AppHandle,
+    manager: State<'_, SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.port
+            .set_break()
+            .map_err(|e| control_error(&manager, &port_name, &mut open, "Failed to set break", e))
+    })();
+    check_disconnect(&app_handle, &manager, &port_name, result)?;
+    
+    tokio::time::sleep(Duration::from_millis(duration_ms)).await;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name).map_err(|_| {
+            SerialError::PortNotOpen("Port was closed while the break was held".to_string())
+        })?;
+        let mut open = entry.lock_or_recover();
+        open.port
+            .clear_break()
+            .map_err(|e| control_error(&manager, &port_name, &mut open, "Failed to clear break", e))
+    })();
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[tauri::command]
+pub fn bytes_available(
+    port_name: String,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<usize, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<usize, SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.port.bytes_to_read().map(|count| count as usize).map_err(|e| {
+            control_error(&manager, &port_name, &mut open, "Failed to query bytes available", e)
+        })
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+#[tauri::command]
+pub fn clear_serial_buffer(
+    port_name: String,
+    which: String,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let buffer = match which.as_str() {
+        "input" => serialport::ClearBuffer::Input,
+        "output" => serialport::ClearBuffer::Output,
+        "all" => serialport::ClearBuffer::All,
+        _ => return Err(SerialError::InvalidInput(format!("Invalid buffer selection: {}", which))),
+    };
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let result = (|| -> Result<(), SerialError> {
+        let entry = manager.port(&port_name)?;
+        let mut open = entry.lock_or_recover();
+        open.port
+            .clear(buffer)
+            .map_err(|e| control_error(&manager, &port_name, &mut open, "Failed to clear buffer", e))
+    })();
+    
+    check_disconnect(&app_handle, &manager, &port_name, result)
+}
+
+// Caps how much a single read on this port returns, whatever buffer size
+// the caller asks for, and preallocates the port's read buffer to match
+#[tauri::command]
+pub fn set_read_buffer_size(
+    port_name: String,
+    size: usize,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    if size == 0 {
+        return Err(SerialError::InvalidInput("Read buffer size must be non-zero".to_string()));
+    }
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    open.max_read_size = Some(size);
+    open.read_buffer = vec![0u8; size];
+    
+    Ok(())
+}
+
+// Passing no watermarks turns the buffer events off
+#[tauri::command]
+pub fn set_buffer_watermarks(
+    port_name: String,
+    watermarks: Option<BufferWatermarks>,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    if let Some(marks) = watermarks {
+        if marks.low >= marks.high {
+            return Err(SerialError::InvalidInput("Low watermark must be below the high watermark".to_string()));
+        }
+    }
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let open = entry.lock_or_recover();
+    
+    *open.watermarks.lock_or_recover() = watermarks;
+    
+    Ok(())
+}
+
+// Frames are decoded from the streamed data, so they only arrive while the
+// port is streaming. The sentinels are stripped from each frame.
+#[tauri::command]
+pub fn set_framing(
+    port_name: String,
+    start_byte: u8,
+    end_byte: u8,
+    max_len: usize,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    if start_byte == end_byte {
+        return Err(SerialError::InvalidInput("Start and end bytes must differ".to_string()));
+    }
+    if max_len == 0 {
+        return Err(SerialError::InvalidInput("Maximum frame length must be non-zero".to_string()));
+    }
+    
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    open.framing = Some(FrameDecoder {
+        start: start_byte,
+        end: end_byte,
+        max_len,
+        frame: None,
+    });
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub fn clear_framing(port_name: String, manager: State<SerialManager>) -> Result<bool, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    Ok(open.framing.take().is_some())
+}
+
+#[tauri::command]
+pub fn set_byte_map(
+    port_name: String,
+    direction: String,
+    map: HashMap<u8, u8>,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let (on_read, on_write) = match direction.to_lowercase().as_str() {
+        "read" => (true, false),
+        "write" => (false, true),
+        "both" => (true, true),
+        _ => return Err(SerialError::InvalidInput(format!("Invalid byte map direction: {}", direction))),
+    };
+    
+    // An empty map removes the translation instead of installing an identity
+    // table that would still be applied to every byte
+    let table = if map.is_empty() {
+        None
+    } else {
+        let mut table = [0u8; 256];
+        for (index, entry) in table.iter_mut().enumerate() {
+            *entry = index as u8;
+        }
+        for (from, to) in map {
+            table[from as usize] = to;
+        }
+        Some(table)
+    };
+    
+    let entry = manager.port(&port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    if on_read {
+        open.read_map = table;
+    }
+    if on_write {
+        open.write_map = table;
+    }
+    
+    Ok(())
+}
+
+fn install_log(
+    manager: &SerialManager,
+    port_name: &str,
+    file_path: &str,
+    compress: bool,
+    format: LogFormat,
+) -> Result<(), SerialError> {
+    let entry = manager.port(port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    if open.log.is_some() {
+        return Err(SerialError::Other("Logging is already active on this port".to_string()));
+    }
+    
+    let log = TrafficLog::create(file_path, compress, format)
+        .map_err(|e| SerialError::IoError(format!("Failed to create log file {}: {}", file_path, e)))?;
+    open.log = Some(log);
+    
+    Ok(())
+}
+
+fn remove_log(manager: &SerialManager, port_name: &str) -> Result<(), SerialError> {
+    let entry = manager.port(port_name)?;
+    let mut open = entry.lock_or_recover();
+    
+    if open.log.is_none() {
+        return Err(SerialError::Other("Logging is not active on this port".to_string()));
+    }
+    
+    open.finish_log()
+        .map_err(|e| SerialError::IoError(format!("Failed to finalize log: {}", e)))
+}
+
+#[tauri::command]
+pub fn start_serial_logging(
+    port_name: String,
+    file_path: String,
+    compress: Option<bool>,
+    timestamped: Option<bool>,
+    binary: Option<bool>,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    
+    let format = match (timestamped.unwrap_or(false), binary.unwrap_or(false)) {
+        (false, _) => LogFormat::Raw,
+        (true, false) => LogFormat::TimestampedText,
+        (true, true) => LogFormat::TimestampedBinary,
+    };
+    
+    install_log(&manager, &port_name, &file_path, compress.unwrap_or(false), format)?;
+    
+    Ok(format!("Logging {} to {}", port_name, file_path))
+}
+
+#[tauri::command]
+pub fn stop_serial_logging(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    remove_log(&manager, &port_name)?;
+    
+    Ok(format!("Logging stopped for {}", port_name))
+}
+
+// Human-readable capture: one timestamped hex line per chunk, flushed as it
+// is written so a crash still leaves a usable log
+#[tauri::command]
+pub fn start_serial_log(
+    port_name: String,
+    file_path: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    install_log(&manager, &port_name, &file_path, false, LogFormat::HexLines)?;
+    
+    Ok(format!("Logging {} to {}", port_name, file_path))
+}
+
+#[tauri::command]
+pub fn stop_serial_log(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    remove_log(&manager, &port_name)?;
+    
+    Ok(format!("Logging stopped for {}", port_name))
+}
+
+#[tauri::command]
+pub fn get_port_activity(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<PortActivity, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let open = entry.lock_or_recover();
+    
+    Ok(PortActivity {
+        last_read_ms: open.last_read_ms,
+        last_write_ms: open.last_write_ms,
+    })
+}
+
+// Counters live on the open port, so reopening starts them from zero
+#[tauri::command]
+pub fn get_serial_stats(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<SerialStats, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let entry = manager.port(&port_name)?;
+    let stats = entry.lock_or_recover().stats();
+    
+    Ok(stats)
+}
+
+#[tauri::command]
+pub fn get_all_serial_stats(
+    manager: State<SerialManager>,
+) -> Result<HashMap<String, SerialStats>, SerialError> {
+    // Every port is held while the stats are read so they are sampled at
+    // the same instant, locking in name order as fire_staged_writes does
+    let entries: BTreeMap<String, SharedPort> = manager.open_ports().into_iter().collect();
+    let ports: BTreeMap<&String, MutexGuard<'_, OpenPort>> = entries
+        .iter()
+        .map(|(name, entry)| (name, entry.lock_or_recover()))
+        .collect();
+    
+    Ok(ports
+        .iter()
+        .map(|(name, open)| ((*name).clone(), open.stats()))
+        .collect())
+}
+
+#[tauri::command]
+pub fn reserve_port(
+    port_name: String,
+    owner_id: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut reservations = manager.reservations.lock_or_recover();
+    
+    match reservations.get(&port_name) {
+        Some(owner) if *owner != owner_id => Err(SerialError::Reserved(format!(
+            "Reserved: port {} is reserved by {}",
+            port_name, owner
+        ))),
+        _ => {
+            reservations.insert(port_name.clone(), owner_id.clone());
+            Ok(format!("Port {} reserved by {}", port_name, owner_id))
+        }
+    }
+}
+
+#[tauri::command]
+pub fn release_port(
+    port_name: String,
+    owner_id: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let mut reservations = manager.reservations.lock_or_recover();
+    
+    match reservations.get(&port_name) {
+        Some(owner) if *owner == owner_id => {
+            reservations.remove(&port_name);
+            Ok(format!("Port {} released", port_name))
+        }
+        Some(owner) => Err(SerialError::Reserved(format!(
+            "Reserved: port {} is reserved by {}",
+            port_name, owner
+        ))),
+        None => Err(SerialError::Other(format!("Port {} is not reserved", port_name))),
+    }
+}
+
+#[tauri::command]
+pub fn get_last_serial_error(
+    port_name: String,
+    manager: State<SerialManager>,
+) -> Result<Option<SerialErrorInfo>, SerialError> {
+    let port_name = manager.resolve_port_name(&port_name)?;
+    let last_errors = manager.last_errors.lock_or_recover();
+    
+    Ok(last_errors.get(&port_name).cloned())
+}
+
+// Takes exactly one of `port_name` or `serial_number`; a serial number is
+// matched against connected USB devices each time the alias is used
+#[tauri::command]
+pub fn set_port_alias(
+    alias: String,
+    port_name: Option<String>,
+    serial_number: Option<String>,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    if alias.trim().is_empty() {
+        return Err(SerialError::InvalidInput("Alias must not be empty".to_string()));
+    }
+    
+    let mut aliases = manager.aliases.lock_or_recover();
+    
+    let (target, description) = match (port_name, serial_number) {
+        (Some(port_name), None) => {
+            // Aliases always point at a real port name, never at another alias
+            if aliases.contains_key(&port_name) {
+                return Err(SerialError::InvalidInput(format!("{} is itself an alias", port_name)));
+            }
+            let description = port_name.clone();
+            (AliasTarget::PortName(port_name), description)
+        }
+        (None, Some(serial_number)) => {
+            let description = format!("serial number {}", serial_number);
+            (AliasTarget::SerialNumber(serial_number), description)
+        }
+        _ => return Err(SerialError::InvalidInput("Specify either a port name or a serial number".to_string())),
+    };
+    
+    aliases.insert(alias.clone(), target);
+    
+    Ok(format!("Alias {} now refers to {}", alias, description))
+}
+
+#[tauri::command]
+pub fn list_port_aliases(
+    manager: State<SerialManager>,
+) -> Result<HashMap<String, AliasTarget>, SerialError> {
+    let aliases = manager.aliases.lock_or_recover();
+    Ok(aliases.clone())
+}
+
+#[tauri::command]
+pub fn remove_port_alias(
+    alias: String,
+    manager: State<SerialManager>,
+) -> Result<String, SerialError> {
+    let mut aliases = manager.aliases.lock_or_recover();
+    
+    if aliases.remove(&alias).is_some() {
+        Ok(format!("Alias {} removed", alias))
+    } else {
+        Err(SerialError::NotFound(format!("Alias {} not found", alias)))
+    }
+}
+
+#[tauri::command]
+pub fn clear_port_aliases(manager: State<SerialManager>) -> Result<(), SerialError> {
+    let mut aliases = manager.aliases.lock_or_recover();
+    aliases.clear();
+    Ok(())
+}
+
+fn serial_profiles_path(app_handle: &AppHandle) -> Result<std::path::PathBuf, SerialError> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| SerialError::IoError(format!("Failed to resolve data directory: {}", e)))?;
+    Ok(data_dir.join(SERIAL_PROFILES_FILE))
+}
+
+// A missing or corrupt file reads as no saved profiles, so a bad edit only
+// costs the user their remembered settings
+fn load_serial_profiles(app_handle: &AppHandle) -> HashMap<String, SerialConfig> {
+    let Ok(path) = serial_profiles_path(app_handle) else {
+        return HashMap::new();
+    };
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return HashMap::new();
+    };
+    
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        eprintln!("⚠️ Ignoring invalid serial profiles in {:?}: {}", path, e);
+        HashMap::new()
+    })
+}
+
+// `identifier` should be the device's USB serial number where it has one,
+// since port names can change between replugs
+#[tauri::command]
+pub fn save_serial_profile(
+    identifier: String,
+    config: SerialConfig,
+    app_handle: AppHandle,
+    manager: State<SerialManager>,
+) -> Result<(), SerialError> {
+    if identifier.trim().is_empty() {
+        return Err(SerialError::InvalidInput("Profile identifier must not be empty".to_string()));
+    }
+    // Don't remember a configuration that open_serial_port would reject
+    build_port("<port>", &config)?;
+    check_timeout(config.timeout_ms)?;
+    
+    let _guard = manager.profiles_lock.lock_or_recover();
+    let mut profiles = load_serial_profiles(&app_handle);
+    profiles.insert(identifier, config);
+    
+    let path = serial_profiles_path(&app_handle)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| SerialError::IoError(format!("Failed to create {:?}: {}", dir, e)))?;
+    }
+    
+    let json = serde_json::to_string_pretty(&profiles)
+        .map_err(|e| SerialError::Other(format!("Failed to serialize serial profiles: {}", e)))?;
+    std::fs::write(&path, json)
+        .map_err(|e| SerialError::IoError(format!("Failed to write {:?}: {}", path, e)))
+}
+
+#[tauri::command]
+pub fn load_serial_profile(
+    identifier: String,
+    app_handle: AppHandle,
+) -> Result<Option<SerialConfig>, SerialError> {
+    Ok(load_serial_profiles(&app_handle).remove(&identifier))
+}
+
+// Accepts whitespace-separated tokens of one or more bytes, each optionally
+// 0x-prefixed, e.g. "0102ff", "01 02 ff" or "0x1B 0x5B 0x41". Errors name the
+// offending token so a typo in a long frame is easy to find.
+fn parse_hex(hex: &str) -> Result<Vec<u8>, SerialError> {
+    let mut bytes = Vec::new();
+    
+    for token in hex.split_whitespace() {
+        let digits = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+            .unwrap_or(token);
+        
+        if let Some(bad) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(SerialError::InvalidInput(format!(
+                "Invalid hex token \"{}\": '{}' is not a hex digit",
+                token, bad
+            )));
+        }
+        if digits.is_empty() || digits.len() % 2 != 0 {
+            return Err(SerialError::InvalidInput(format!(
+                "Invalid hex token \"{}\": expected an even, non-zero number of digits",
+                token
+            )));
+        }
+        
+        // All ASCII by now, so byte offsets are char boundaries
+        for i in (0..digits.len()).step_by(2) {
+            let byte = u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|e| SerialError::InvalidInput(format!("Invalid hex token \"{}\": {}", token, e)))?;
+            bytes.push(byte);
+        }
+    }
+    
+    Ok(bytes)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanResult {
+    pub port_name: String,
+    pub responded: bool,
+    pub response_hex: String,
+    pub error: Option<String>,
+}
+
+// Probes bypass the manager so each port reads on its own thread without
+// contending for the port locks
+fn probe_port(
+    port_name: &str,
+    config: &SerialConfig,
+    probe: &[u8],
+    wait: Duration,
+) -> Result<Vec<u8>, SerialError> {
+    let mut port = open_port(build_port(port_name, config)?, config.exclusive)
+        .map_err(|e| SerialError::IoError(format!("Failed to open port: {}", e)))?;
+    
+    if !probe.is_empty() {
+        port.write_all(probe)
+            .and_then(|_| port.flush())
+            .map_err(|e| SerialError::IoError(format!("Failed to write probe: {}", e)))?;
+    }
+    
+    let deadline = Instant::now() + wait;
+    let mut response = Vec::new();
+    let mut buffer = vec![0u8; LISTENER_BUFFER_SIZE];
+    
+    while Instant::now() < deadline {
+        match port.read(&mut buffer) {
+            Ok(bytes_read) => response.extend_from_slice(&buffer[..bytes_read]),
+            Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {}
+            Err(e) => return Err(SerialError::IoError(format!("Failed to read from port: {}", e))),
+        }
+    }
+    
+    Ok(response)
+}
+
+// Ports the app already has open or reserved are reported rather than
+// probed, so a scan never disturbs a live session
+#[tauri::command]
+pub async fn scan_for_devices(
+    probe_hex: Option<String>,
+    wait_ms: u64,
+    config: Option<SerialConfig>,
+    app_handle: AppHandle,
+) -> Result<Vec<ScanResult>, SerialError> {
+    let probe = match probe_hex {
+        Some(hex) => parse_hex(&hex)?,
+        None => Vec::new(),
+    };
+    let config = config.unwrap_or(SerialConfig {
+        baud_rate: 9600,
+        data_bits: 8,
+        stop_bits: 1,
+        parity: "none".to_string(),
+        exclusive: None,
+        timeout_ms: None,
+        flow_control: default_flow_control(),
+    });
+    check_timeout(config.timeout_ms)?;
+    let wait = Duration::from_millis(wait_ms);
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let manager: State<SerialManager> = app_handle.state();
+        
+        let port_names: Vec<String> = serialport::available_ports()
+            .map_err(|e| SerialError::IoError(e.to_string()))?
+            .into_iter()
+            .map(|port| port.port_name)
+            .collect();
+        let open_names: Vec<String> = manager
+            .ports
+            .lock_or_recover()
+            .keys()
+            .cloned()
+            .collect();
+        
+        let outcomes: Vec<Result<Vec<u8>, SerialError>> = thread::scope(|scope| {
+            let handles: Vec<_> = port_names
+                .iter()
+                .map(|port_name| {
+                    let busy = if open_names.contains(port_name) {
+                        Some(SerialError::PortBusy("Port is already open".to_string()))
+                    } else {
+                        manager.check_reservation(port_name, None).err()
+                    };
+                    let (config, probe) = (&config, &probe);
+                    
+                    scope.spawn(move || match busy {
+                        Some(message) => Err(message),
+                        None => probe_port(port_name, config, probe, wait),
+                    })
+                })
+                .collect();
+            
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| Err(SerialError::Other("Probe thread panicked".to_string())))
+                })
+                .collect()
+        });
+        
+        Ok(port_names
+            .into_iter()
+            .zip(outcomes)
+            .map(|(port_name, outcome)| match outcome {
+                Ok(response) => ScanResult {
+                    port_name,
+                    responded: !response.is_empty(),
+                    response_hex: to_hex(&response),
+                    error: None,
+                },
+                Err(error) => ScanResult {
+                    port_name,
+                    responded: false,
+                    response_hex: String::new(),
+                    error: Some(error.to_string()),
+                },
+            })
+            .collect())
+    })
+    .await
+    .map_err(|e| SerialError::Other(format!("Scan failed: {}", e)))?
+}
+
+#[tauri::command]
+pub fn reset_serial_subsystem(manager: State<SerialManager>) -> Result<(), SerialError> {
+    manager.reset()
+}
+
+#[tauri::command]
+pub fn serial_thread_count(manager: State<SerialManager>) -> usize {
+    manager.active_thread_count()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessStatus {
+    pub ok: bool,
+    pub hint: String,
+    pub inaccessible_ports: Vec<String>,
+}
+
+// Serial devices on most distros are root:dialout (uucp on Arch) with mode
+// 660. This only inspects ownership and mode bits: opening the devices to
+// test them would toggle DTR and reset some boards.
+#[cfg(target_os = "linux")]
+fn check_linux_serial_access() -> Result<AccessStatus, SerialError> {
+    use std::os::unix::fs::MetadataExt;
+    
+    let status = std::fs::read_to_string("/proc/self/status")
+        .map_err(|e| SerialError::IoError(format!("Failed to read process status: {}", e)))?;
+    let field = |name: &str| -> Vec<u32> {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .map(|rest| rest.split_whitespace().filter_map(|v| v.parse().ok()).collect())
+            .unwrap_or_default()
+    };
+    
+    // Effective uid/gid are the second column of the Uid:/Gid: lines
+    let uid = field("Uid:").get(1).copied().unwrap_or(u32::MAX);
+    let mut gids = field("Groups:");
+    gids.extend(field("Gid:").get(1));
+    
+    if uid == 0 {
+        return Ok(AccessStatus {
+            ok: true,
+            hint: "Running as root".to_string(),
+            inaccessible_ports: Vec::new(),
+        });
+    }
+    
+    let groups = std::fs::read_to_string("/etc/group").unwrap_or_default();
+    let serial_groups: Vec<(String, u32)> = groups
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split(':');
+            let name = fields.next()?;
+            let gid = fields.nth(1)?.parse().ok()?;
+            matches!(name, "dialout" | "uucp").then(|| (name.to_string(), gid))
+        })
+        .collect();
+    let in_serial_group = serial_groups.iter().any(|(_, gid)| gids.contains(gid));
+    
+    let mut devices = Vec::new();
+    if let Ok(entries) = std::fs::read_dir("/dev") {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if name.starts_with("ttyUSB") || name.starts_with("ttyACM") {
+                devices.push((format!("/dev/{}", name), entry.metadata()));
+            }
+        }
+    }
+    
+    let inaccessible_ports: Vec<String> = devices
+        .into_iter()
+        .filter(|(_, metadata)| match metadata {
+            Ok(metadata) => {
+                let mode = metadata.mode();
+                let allowed = if metadata.uid() == uid {
+                    mode & 0o600 == 0o600
+                } else if gids.contains(&metadata.gid()) {
+                    mode & 0o060 == 0o060
+                } else {
+                    mode & 0o006 == 0o006
+                };
+                !allowed
+            }
+            Err(_) => true,
+        })
+        .map(|(path, _)| path)
+        .collect();
+    
+    let group_name = serial_groups
+        .first()
+        .map(|(name, _)| name.clone())
+        .unwrap_or_else(|| "dialout".to_string());
+    
+    let (ok, hint) = if !inaccessible_ports.is_empty() {
+        (
+            false,
+            format!(
+                "Some serial ports are not accessible. Add your user to the {} group with `sudo usermod -aG {} $USER`, then log out and back in.",
+                group_name, group_name
+            ),
+        )
+    } else if in_serial_group {
+        (true, format!("User is a member of the {} group", group_name))
+    } else {
+        (
+            false,
+            format!(
+                "Your user is not in the {} group, so newly connected devices will likely be inaccessible. Run `sudo usermod -aG {} $USER`, then log out and back in.",
+                group_name, group_name
+            ),
+        )
+    };
+    
+    Ok(AccessStatus {
+        ok,
+        hint,
+        inaccessible_ports,
+    })
+}
+
+#[tauri::command]
+pub fn check_serial_access() -> Result<AccessStatus, SerialError> {
+    #[cfg(target_os = "linux")]
+    {
+        check_linux_serial_access()
+    }
+    
+    #[cfg(not(target_os = "linux"))]
+    {
+        Ok(AccessStatus {
+            ok: true,
+            hint: "Serial ports don't require special permissions on this platform".to_string(),
+            inaccessible_ports: Vec::new(),
+        })
+    }
+}
+
+// Fastest rate of common USB-serial bridges (FT232H and friends)
+const MAX_BAUD_RATE: u32 = 12_000_000;
+
+#[tauri::command]
+pub fn validate_baud_rate(rate: u32) -> Result<(), SerialError> {
+    if rate == 0 {
+        return Err(SerialError::InvalidConfig("Baud rate must be greater than zero".to_string()));
+    }
+    if rate > MAX_BAUD_RATE {
+        return Err(SerialError::InvalidConfig(format!("Baud rate {} exceeds the maximum of {}", rate, MAX_BAUD_RATE)));
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn add_custom_baud_rate(rate: u32, manager: State<SerialManager>) -> Result<(), SerialError> {
+    validate_baud_rate(rate)?;
+    manager
+        .custom_baud_rates
+        .lock_or_recover()
+        .insert(rate);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_custom_baud_rate(rate: u32, manager: State<SerialManager>) -> Result<bool, SerialError> {
+    Ok(manager
+        .custom_baud_rates
+        .lock_or_recover()
+        .remove(&rate))
+}
+
+#[tauri::command]
+pub fn get_available_baud_rates(
+    include_custom: Option<bool>,
+    manager: State<SerialManager>,
+) -> Result<Vec<u32>, SerialError> {
+    let mut rates = vec![
+        300, 1200, 2400, 4800, 9600, 19200, 38400, 57600, 115200, 230400, 460800, 921600,
+    ];
+    
+    if include_custom.unwrap_or(true) {
+        let custom = manager.custom_baud_rates.lock_or_recover();
+        for &rate in custom.iter() {
+            if !rates.contains(&rate) {
+                rates.push(rate);
+            }
+        }
+        rates.sort_unstable();
+    }
+    
+    Ok(rates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+
+    fn poison<T>(mutex: &Mutex<T>) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _guard = mutex.lock().unwrap();
+            panic!("simulated panic while holding the lock");
+        }));
+        assert!(mutex.is_poisoned());
+    }
+
+    #[test]
+    fn poisoned_locks_are_recovered() {
+        let manager = SerialManager::new();
+        manager.aliases.lock_or_recover().insert(
+            "sensor".to_string(),
+            AliasTarget::PortName("/dev/ttyUSB0".to_string()),
+        );
+        
+        poison(&manager.aliases);
+        poison(&manager.ports);
+        poison(&manager.last_errors);
+        
+        assert_eq!(manager.resolve_port_name("sensor").unwrap(), "/dev/ttyUSB0");
+        assert!(manager.open_port_summaries().unwrap().is_empty());
+        
+        manager.record_error("/dev/ttyUSB0", SerialError::IoError("boom".to_string()), Some(5));
+        let errors = manager.last_errors_snapshot().unwrap();
+        assert_eq!(errors["/dev/ttyUSB0"].os_code, Some(5));
+    }
+
+    #[test]
+    fn reset_clears_poisoned_state() {
+        let manager = SerialManager::new();
+        manager
+            .reservations
+            .lock_or_recover()
+            .insert("/dev/ttyUSB0".to_string(), "owner".to_string());
+        
+        poison(&manager.ports);
+        poison(&manager.reservations);
+        
+        manager.reset().unwrap();
+        
+        assert!(!manager.ports.is_poisoned());
+        assert!(!manager.reservations.is_poisoned());
+        assert!(manager.check_reservation("/dev/ttyUSB0", None).is_ok());
+    }
+
+    // Accepts at most `chunk` bytes per write, then fails once `fail_after`
+    // bytes have gone through
+    struct ShortWriter {
+        written: Vec<u8>,
+        chunk: usize,
+        fail_after: Option<usize>,
+    }
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if self.fail_after.is_some_and(|limit| self.written.len() >= limit) {
+                return Err(io::Error::new(io::ErrorKind::BrokenPipe, "device gone"));
+            }
+            let count = buf.len().min(self.chunk);
+            self.written.extend_from_slice(&buf[..count]);
+            Ok(count)
+        }
+        
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn short_writes_send_the_remainder() {
+        let mut writer = ShortWriter {
+            written: Vec::new(),
+            chunk: 3,
+            fail_after: None,
+        };
+        let data = b"AT+RESET\r\n";
+        
+        assert!(write_fully(&mut writer, data).is_ok());
+        assert_eq!(writer.written, data);
+    }
+
+    #[test]
+    fn failed_writes_report_what_was_sent() {
+        let mut writer = ShortWriter {
+            written: Vec::new(),
+            chunk: 4,
+            fail_after: Some(4),
+        };
+        
+        let (sent, e) = write_fully(&mut writer, b"0123456789").unwrap_err();
+        assert_eq!(sent, 4);
+        assert_eq!(e.kind(), io::ErrorKind::BrokenPipe);
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn ebusy_opens_are_reported_as_busy() {
+        let busy = serialport::Error::new(serialport::ErrorKind::Unknown, "Device or resource busy");
+        assert!(matches!(classify_open_error(&busy, Some(16)), SerialError::PortBusy(_)));
+        
+        let missing = serialport::Error::new(serialport::ErrorKind::NoDevice, "No such device");
+        assert!(matches!(classify_open_error(&missing, Some(19)), SerialError::NotFound(_)));
+    }
+
+    #[test]
+    fn cancel_stops_a_read_in_progress() {
+        let manager = Arc::new(SerialManager::new());
+        let reads = Arc::new(AtomicUsize::new(0));
+        
+        let reader = {
+            let manager = manager.clone();
+            let reads = reads.clone();
+            thread::spawn(move || {
+                let deadline = Instant::now() + Duration::from_secs(30);
+                collect_reads(
+                    &manager,
+                    "/dev/ttyUSB0",
+                    16,
+                    16,
+                    deadline,
+                    |_, _| {
+                        // Stands in for a port timeout with no data
+                        reads.fetch_add(1, Ordering::SeqCst);
+                        thread::sleep(Duration::from_millis(5));
+                        Ok(0)
+                    },
+                    |_| {},
+                )
+            })
+        };
+        
+        while reads.load(Ordering::SeqCst) == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+        manager.read_cancels.lock_or_recover().insert("/dev/ttyUSB0".to_string());
+        
+        let started = Instant::now();
+        let result = reader.join().unwrap();
+        assert!(matches!(result, Err(SerialError::Cancelled(_))));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
 }
@@ -0,0 +1,63 @@
+use serde::Serialize;
+use std::fmt;
+
+// Serialized as { "code": "port_not_open", "message": "..." } so the
+// frontend can branch on a stable code instead of the message text
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "code", content = "message", rename_all = "snake_case")]
+pub enum SerialError {
+    PortNotOpen(String),
+    PortBusy(String),
+    Disconnected(String),
+    IoError(String),
+    InvalidConfig(String),
+    InvalidInput(String),
+    Reserved(String),
+    NotFound(String),
+    Cancelled(String),
+    // Anything not yet given a more specific code
+    Other(String),
+}
+
+impl SerialError {
+    pub fn port_not_open() -> Self {
+        SerialError::PortNotOpen("Port not open".to_string())
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            SerialError::PortNotOpen(message)
+            | SerialError::PortBusy(message)
+            | SerialError::Disconnected(message)
+            | SerialError::IoError(message)
+            | SerialError::InvalidConfig(message)
+            | SerialError::InvalidInput(message)
+            | SerialError::Reserved(message)
+            | SerialError::NotFound(message)
+            | SerialError::Cancelled(message)
+            | SerialError::Other(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for SerialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for SerialError {}
+
+impl From<String> for SerialError {
+    fn from(message: String) -> Self {
+        SerialError::Other(message)
+    }
+}
+
+// For the non-serial commands (diagnostics, shutdown) that still report
+// plain strings
+impl From<SerialError> for String {
+    fn from(error: SerialError) -> Self {
+        error.message().to_string()
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::{SecondsFormat, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Rx,
+    Tx,
+}
+
+impl Direction {
+    fn marker(self) -> &'static str {
+        match self {
+            Direction::Rx => "RX",
+            Direction::Tx => "TX",
+        }
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Direction::Rx => 0,
+            Direction::Tx => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    // Bytes exactly as they crossed the wire, both directions interleaved
+    Raw,
+    // "<ISO-8601 timestamp> <RX|TX>" header line before each chunk
+    TimestampedText,
+    // Per chunk: u64 epoch millis, u8 direction (0 = RX, 1 = TX),
+    // u32 payload length, then the payload; integers are big-endian
+    TimestampedBinary,
+    // One "<ISO-8601 timestamp> <RX|TX> <hex bytes>" line per chunk
+    HexLines,
+}
+
+enum LogSink {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<File>),
+}
+
+// Destination for captured traffic. Gzip streams must be finished explicitly,
+// otherwise the trailer is missing and the file can't be decompressed.
+pub struct TrafficLog {
+    sink: LogSink,
+    format: LogFormat,
+}
+
+impl TrafficLog {
+    pub fn create(file_path: &str, compress: bool, format: LogFormat) -> io::Result<Self> {
+        let file = File::create(file_path)?;
+        
+        let sink = if compress {
+            LogSink::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            LogSink::Plain(BufWriter::new(file))
+        };
+        
+        Ok(TrafficLog { sink, format })
+    }
+
+    pub fn write_chunk(&mut self, direction: Direction, bytes: &[u8]) -> io::Result<()> {
+        let writer: &mut dyn Write = match &mut self.sink {
+            LogSink::Plain(writer) => writer,
+            LogSink::Gzip(encoder) => encoder,
+        };
+        
+        match self.format {
+            LogFormat::Raw => writer.write_all(bytes)?,
+            LogFormat::TimestampedText => {
+                let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+                writeln!(writer, "{} {}", timestamp, direction.marker())?;
+                writer.write_all(bytes)?;
+                if !bytes.ends_with(b"\n") {
+                    writer.write_all(b"\n")?;
+                }
+            }
+            LogFormat::TimestampedBinary => {
+                let millis = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or(0);
+                writer.write_all(&millis.to_be_bytes())?;
+                writer.write_all(&[direction.code()])?;
+                writer.write_all(&(bytes.len() as u32).to_be_bytes())?;
+                writer.write_all(bytes)?;
+            }
+            LogFormat::HexLines => {
+                let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+                let hex: Vec<String> = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+                writeln!(writer, "{} {} {}", timestamp, direction.marker(), hex.join(" "))?;
+            }
+        }
+        
+        // Flushing a gzip stream mid-way costs compression, so only plain
+        // files are flushed per chunk
+        match &mut self.sink {
+            LogSink::Plain(writer) => writer.flush(),
+            LogSink::Gzip(_) => Ok(()),
+        }
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        match self.sink {
+            LogSink::Plain(mut writer) => writer.flush(),
+            LogSink::Gzip(encoder) => encoder.finish().map(|_| ()),
+        }
+    }
+}
@@ -1,41 +1,340 @@
-use tauri::Manager;
-use std::process::{Command, Child};
-use std::sync::Mutex;
+use tauri::{Emitter, Manager};
+use serde::{Deserialize, Serialize};
+use std::process::{Command, Child, Stdio};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::path::PathBuf;
 use std::env;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::thread::{self, JoinHandle};
+
+// Lines of server output kept in memory
+const SERVER_LOG_CAPACITY: usize = 1000;
+
+// Used when a command isn't given an instance id, so single-server callers
+// don't need to know about instances
+pub const DEFAULT_INSTANCE_ID: &str = "default";
+
+// Looked up on PATH unless a path is configured; the .cmd is required on
+// Windows
+#[cfg(target_os = "windows")]
+const DEFAULT_NPM_COMMAND: &str = "npm.cmd";
+#[cfg(not(target_os = "windows"))]
+const DEFAULT_NPM_COMMAND: &str = "npm";
+const DEFAULT_NODE_COMMAND: &str = "node";
+
+// Oldest Node.js major version the backend supports
+const MIN_NODE_MAJOR: u32 = 18;
+
+// Must match the defaults in server/src/server.ts
+const SERVER_HOST: &str = "localhost";
+const SERVER_PORT: u16 = 5000;
+const HEALTH_PATH: &str = "/api/health";
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+
+const DEFAULT_SUPERVISOR_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_SUPERVISOR_INTERVAL_MS: u64 = 5000;
+const DEFAULT_SUPERVISOR_WINDOW_MS: u64 = 60_000;
+const SUPERVISOR_STOP_POLL: Duration = Duration::from_millis(100);
+
+// How long the server gets to shut down cleanly before it's force-killed,
+// and how long after that before we give up on it
+const SERVER_GRACEFUL_TIMEOUT: Duration = Duration::from_secs(5);
+const SERVER_EXIT_TIMEOUT: Duration = Duration::from_secs(5);
+const SERVER_EXIT_POLL: Duration = Duration::from_millis(50);
+
+const CRASH_MONITOR_INTERVAL: Duration = Duration::from_millis(500);
+
+// `npm run dev` compiles on startup, so allow it a generous wait
+const DEFAULT_READY_TIMEOUT_MS: u64 = 30_000;
+const READY_POLL: Duration = Duration::from_millis(250);
+
+// Stored in the app config dir and read once at startup
+const AUTO_START_SETTINGS_FILE: &str = "server-auto-start.json";
+const DEFAULT_AUTO_START_DELAY_MS: u64 = 1000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerLogLine {
+    pub instance: String,
+    pub stream: String,
+    pub line: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ServerHealthEvent {
+    instance: String,
+    consecutive_failures: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct ServerCrashEvent {
+    instance: String,
+    // None when the process was killed by a signal
+    exit_code: Option<i32>,
+    restarting: bool,
+}
+
+// Restarts a server whose process is alive but has stopped answering its
+// health endpoint
+struct Supervisor {
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+// With no explicit host or port the probe follows the configured server
+// host and whatever port the server was started on
+#[derive(Debug, Clone)]
+struct HealthEndpoint {
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+}
+
+impl Default for HealthEndpoint {
+    fn default() -> Self {
+        HealthEndpoint {
+            host: None,
+            port: None,
+            path: HEALTH_PATH.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerStatusInfo {
+    pub status: String,
+    pub pid: Option<u32>,
+    pub uptime_secs: Option<u64>,
+    pub port: Option<u16>,
+}
+
+pub struct ServerInstance {
+    pub process: Child,
+    pub launch_command: String,
+    pub port: u16,
+    pub started_at: Instant,
+    // Tells this launch apart from a later one under the same id
+    generation: u64,
+}
 
 pub struct ServerState {
-    pub process: Mutex<Option<Child>>,
+    pub instances: Mutex<HashMap<String, ServerInstance>>,
+    pub logs: Arc<Mutex<VecDeque<ServerLogLine>>>,
+    // Kept after an instance stops so it can be restarted as it was
+    last_options: Mutex<HashMap<String, LaunchOptions>>,
+    supervisor: Mutex<Option<Supervisor>>,
+    health_endpoint: Mutex<HealthEndpoint>,
+    // Where the backend is reached, e.g. 127.0.0.1 for a server that
+    // doesn't bind localhost's IPv6 address
+    host: Mutex<String>,
+    auto_restart: AtomicBool,
+    // Bumped on every launch so a crash monitor can tell its own process
+    // from one started after it
+    generation: AtomicU64,
 }
 
 impl ServerState {
     pub fn new() -> Self {
         ServerState {
-            process: Mutex::new(None),
+            instances: Mutex::new(HashMap::new()),
+            logs: Arc::new(Mutex::new(VecDeque::with_capacity(SERVER_LOG_CAPACITY))),
+            last_options: Mutex::new(HashMap::new()),
+            supervisor: Mutex::new(None),
+            health_endpoint: Mutex::new(HealthEndpoint::default()),
+            host: Mutex::new(SERVER_HOST.to_string()),
+            auto_restart: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
         }
     }
 }
 
 impl Drop for ServerState {
     fn drop(&mut self) {
-        if let Ok(mut process) = self.process.lock() {
-            if let Some(child) = process.take() {
-                #[cfg(target_os = "windows")]
-                {
-                    let _ = Command::new("taskkill")
-                        .args(["/F", "/T", "/PID", &child.id().to_string()])
-                        .spawn();
-                }
-                
-                #[cfg(not(target_os = "windows"))]
-                {
-                    let _ = child.kill();
+        if let Ok(mut instances) = self.instances.lock() {
+            for (id, mut instance) in instances.drain() {
+                let _ = terminate_child(&mut instance.process);
+                println!("🛑 Backend server {} stopped", id);
+            }
+        }
+    }
+}
+
+fn instance_id(id: Option<String>) -> String {
+    id.unwrap_or_else(|| DEFAULT_INSTANCE_ID.to_string())
+}
+
+// Ask the server to shut down, force-kill it if it hasn't exited after
+// SERVER_GRACEFUL_TIMEOUT, and wait on it either way so the process is reaped
+// rather than left behind as a zombie. Errors if it's still alive after that
+fn terminate_child(child: &mut Child) -> Result<(), String> {
+    let pid = child.id().to_string();
+    
+    #[cfg(target_os = "windows")]
+    {
+        // Without /F taskkill sends a close request to the process tree
+        let _ = Command::new("taskkill")
+            .args(["/T", "/PID", &pid])
+            .status();
+    }
+    
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = Command::new("kill")
+            .args(["-TERM", &pid])
+            .status();
+    }
+    
+    if wait_for_exit(child, SERVER_GRACEFUL_TIMEOUT)? {
+        return Ok(());
+    }
+    
+    println!("⚠️ Server did not shut down in time, killing it");
+    
+    #[cfg(target_os = "windows")]
+    {
+        // On Windows, kill the process tree
+        let _ = Command::new("taskkill")
+            .args(["/F", "/T", "/PID", &pid])
+            .status();
+    }
+    
+    #[cfg(not(target_os = "windows"))]
+    {
+        child.kill().map_err(|e| format!("Failed to stop server: {}", e))?;
+    }
+    
+    if !wait_for_exit(child, SERVER_EXIT_TIMEOUT)? {
+        return Err(format!(
+            "Server (pid {}) did not exit within {} seconds of being killed",
+            pid,
+            SERVER_EXIT_TIMEOUT.as_secs()
+        ));
+    }
+    
+    Ok(())
+}
+
+// Returns whether the child exited (and was reaped) before the timeout
+fn wait_for_exit(child: &mut Child, timeout: Duration) -> Result<bool, String> {
+    let deadline = Instant::now() + timeout;
+    
+    loop {
+        match child.try_wait() {
+            Ok(Some(_)) => return Ok(true),
+            Ok(None) if Instant::now() >= deadline => return Ok(false),
+            Ok(None) => thread::sleep(SERVER_EXIT_POLL),
+            Err(e) => return Err(format!("Failed to wait for server to exit: {}", e)),
+        }
+    }
+}
+
+// Overrides for how the backend is launched; anything left as None falls
+// back to the auto-detected default
+#[derive(Debug, Clone, Default)]
+pub struct LaunchOptions {
+    pub working_dir: Option<String>,
+    // Relative to the working directory; run directly with node
+    pub script: Option<String>,
+    pub port: Option<u16>,
+    pub env: HashMap<String, String>,
+    pub node_path: Option<String>,
+    pub npm_path: Option<String>,
+    pub ready_timeout_ms: Option<u64>,
+}
+
+// Forward one of the server's output streams to our own console (as before
+// it was piped) and to the frontend as server-log events, while keeping the
+// most recent lines in the ring buffer
+fn capture_output<R: Read + Send + 'static>(
+    app_handle: tauri::AppHandle,
+    instance: String,
+    reader: R,
+    stream: &'static str,
+    logs: Arc<Mutex<VecDeque<ServerLogLine>>>,
+) {
+    thread::spawn(move || {
+        for line in BufReader::new(reader).split(b'\n') {
+            let Ok(bytes) = line else { break };
+            let line = String::from_utf8_lossy(&bytes).trim_end_matches('\r').to_string();
+            
+            if stream == "stderr" {
+                eprintln!("{}", line);
+            } else {
+                println!("{}", line);
+            }
+            
+            let entry = ServerLogLine {
+                instance: instance.clone(),
+                stream: stream.to_string(),
+                line,
+            };
+            let _ = app_handle.emit("server-log", entry.clone());
+            
+            if let Ok(mut logs) = logs.lock() {
+                if logs.len() == SERVER_LOG_CAPACITY {
+                    logs.pop_front();
                 }
-                
-                println!("🛑 Backend server stopped");
+                logs.push_back(entry);
             }
         }
+    });
+}
+
+// Returns the version string reported by `node --version`, e.g. "v20.11.1",
+// or a message the user can act on if it's missing or too old
+fn node_version(node_command: &str) -> Result<String, String> {
+    let output = Command::new(node_command)
+        .arg("--version")
+        .output()
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!("Node.js not found (tried {})", node_command)
+            } else {
+                format!("Failed to run {} --version: {}", node_command, e)
+            }
+        })?;
+    
+    if !output.status.success() {
+        return Err(format!("{} --version failed ({})", node_command, output.status));
+    }
+    
+    let version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let major = version
+        .trim_start_matches('v')
+        .split('.')
+        .next()
+        .and_then(|major| major.parse::<u32>().ok())
+        .ok_or_else(|| format!("Could not parse Node.js version: {:?}", version))?;
+    
+    if major < MIN_NODE_MAJOR {
+        return Err(format!("Node.js {}+ required, found {}", MIN_NODE_MAJOR, version));
     }
+    
+    Ok(version)
+}
+
+#[tauri::command]
+pub fn check_node_version(node_path: Option<String>) -> Result<String, String> {
+    node_version(node_path.as_deref().unwrap_or(DEFAULT_NODE_COMMAND))
+}
+
+// Binding is the only reliable check, the listener is dropped straight away.
+// Node listens on every interface, so both wildcard addresses are tried;
+// failures other than AddrInUse (e.g. no IPv6) don't prove a conflict
+fn check_port_free(port: u16) -> Result<(), String> {
+    for host in ["0.0.0.0", "::"] {
+        if let Err(e) = TcpListener::bind((host, port)) {
+            if e.kind() == std::io::ErrorKind::AddrInUse {
+                return Err(format!("Port {} already in use", port));
+            }
+        }
+    }
+    
+    Ok(())
 }
 
 fn get_server_path(_app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
@@ -71,98 +370,903 @@ fn get_server_path(_app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
     }
 }
 
-pub fn start_backend_server_internal(app_handle: tauri::AppHandle) -> Result<String, String> {
+pub fn start_backend_server_internal(
+    app_handle: tauri::AppHandle,
+    id: &str,
+    options: &LaunchOptions,
+) -> Result<String, String> {
     let state: tauri::State<ServerState> = app_handle.state();
-    let mut process_lock = state.process.lock().unwrap();
+    let mut instances = state.instances.lock().unwrap();
     
     // Check if server is already running
-    if process_lock.is_some() {
-        return Ok("Server is already running".to_string());
+    if instances.contains_key(id) {
+        return Ok(format!("Server {} is already running", id));
     }
     
-    let server_path = get_server_path(&app_handle)?;
+    let server_path = match &options.working_dir {
+        Some(dir) => {
+            let path = PathBuf::from(dir);
+            if !path.is_dir() {
+                return Err(format!("Working directory not found: {:?}", path));
+            }
+            path
+        }
+        None => get_server_path(&app_handle)?,
+    };
     
     if !server_path.exists() {
         return Err(format!("Server directory not found at: {:?}", server_path));
     }
     
+    let node = options.node_path.as_deref().unwrap_or(DEFAULT_NODE_COMMAND);
+    node_version(node)?;
+    
     // Determine which script to run
-    let server_script = if cfg!(debug_assertions) {
+    let server_script = match &options.script {
+        Some(script) => script.as_str(),
         // Development: run from src
-        "src/server.ts"
-    } else {
+        None if cfg!(debug_assertions) => "src/server.ts",
         // Production: run compiled version
-        "dist/server.js"
+        None => "dist/server.js",
     };
     
-    #[cfg(target_os = "windows")]
-    let (node_command, args) = if cfg!(debug_assertions) {
-        // Development: use npm.cmd (the .cmd is required on Windows)
-        ("npm.cmd", vec!["run", "dev"])
-    } else {
-        // Production: use node
-        ("node", vec![server_script])
-    };
+    // Node would otherwise fail with a stack trace about a missing module
+    let script_path = server_path.join(server_script);
+    if !script_path.is_file() {
+        return Err(format!("Server script not found: {:?}", script_path));
+    }
     
-    #[cfg(not(target_os = "windows"))]
-    let (node_command, args) = if cfg!(debug_assertions) {
-        ("npm", vec!["run", "dev"])
+    // Development: use npm, production: use node. An explicit script always
+    // runs under node. A configured path wins over looking the command up
+    // on PATH
+    let (node_command, args) = if cfg!(debug_assertions) && options.script.is_none() {
+        let npm = options.npm_path.clone().unwrap_or_else(|| DEFAULT_NPM_COMMAND.to_string());
+        (npm, vec!["run", "dev"])
     } else {
-        ("node", vec![server_script])
+        let node = options.node_path.clone().unwrap_or_else(|| DEFAULT_NODE_COMMAND.to_string());
+        (node, vec![server_script])
     };
     
-    println!("Starting server from: {:?}", server_path);
-    println!("Using command: {} {:?}", node_command, args);
+    let port = options.port.unwrap_or(SERVER_PORT);
+    
+    // Node would otherwise start and die straight away on EADDRINUSE, which
+    // we'd report as a successful start
+    check_port_free(port)?;
+    
+    println!("Starting server {} from: {:?}", id, server_path);
+    println!("Using command: {} {:?} on port {}", node_command, args, port);
+    
+    // Only the names are printed, the values may be secrets
+    if !options.env.is_empty() {
+        let mut names: Vec<&String> = options.env.keys().collect();
+        names.sort();
+        println!("With environment overrides: {:?}", names);
+    }
     
-    let child = Command::new(node_command)
+    // server.ts reads its listen port from PORT, which is set last so it
+    // always matches the port we record
+    let mut child = Command::new(&node_command)
         .args(&args)
         .current_dir(&server_path)
+        .envs(&options.env)
+        .env("PORT", port.to_string())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
         .spawn()
-        .map_err(|e| format!("Failed to start server: {}. Make sure Node.js is installed.", e))?;
+        .map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                format!(
+                    "Failed to start server: command not found: {}. Make sure Node.js is installed or set its path.",
+                    node_command
+                )
+            } else {
+                format!("Failed to start server with {}: {}", node_command, e)
+            }
+        })?;
+    
+    if let Some(stdout) = child.stdout.take() {
+        capture_output(app_handle.clone(), id.to_string(), stdout, "stdout", state.logs.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        capture_output(app_handle.clone(), id.to_string(), stderr, "stderr", state.logs.clone());
+    }
+    
+    let generation = state.generation.fetch_add(1, Ordering::SeqCst) + 1;
+    instances.insert(
+        id.to_string(),
+        ServerInstance {
+            process: child,
+            launch_command: format!("{} {} (in {:?})", node_command, args.join(" "), server_path),
+            port,
+            started_at: Instant::now(),
+            generation,
+        },
+    );
     
-    *process_lock = Some(child);
+    {
+        let app_handle = app_handle.clone();
+        let id = id.to_string();
+        thread::spawn(move || monitor_server_process(app_handle, id, generation));
+    }
+    
+    if let Ok(mut last_options) = state.last_options.lock() {
+        last_options.insert(id.to_string(), options.clone());
+    }
+    
+    // Don't hold the instances lock while waiting, status checks need it
+    drop(instances);
+    
+    let ready_timeout = Duration::from_millis(options.ready_timeout_ms.unwrap_or(DEFAULT_READY_TIMEOUT_MS));
+    if let Err(e) = wait_until_ready(&state, id, generation, ready_timeout) {
+        // Only tear down our own launch, not one that replaced it meanwhile
+        if live_instance(&state, id).is_some_and(|live| live.generation == generation) {
+            let _ = stop_backend_server_internal(&app_handle, id);
+        }
+        return Err(e);
+    }
+    
+    Ok(format!("Server {} started successfully from {:?}", id, server_path))
+}
+
+fn wait_until_ready(
+    state: &ServerState,
+    id: &str,
+    generation: u64,
+    timeout: Duration,
+) -> Result<(), String> {
+    let deadline = Instant::now() + timeout;
     
-    Ok(format!("Server started successfully from {:?}", server_path))
+    loop {
+        let port = match live_instance(state, id) {
+            Some(live) if live.generation == generation => live.port,
+            _ => return Err(format!("Server {} exited before it became ready", id)),
+        };
+        
+        if server_healthy(state, port) {
+            return Ok(());
+        }
+        
+        if Instant::now() >= deadline {
+            return Err(format!(
+                "Server {} did not become ready within {} ms",
+                id,
+                timeout.as_millis()
+            ));
+        }
+        
+        thread::sleep(READY_POLL);
+    }
 }
 
+// Async so waiting for the server to become ready doesn't block the main
+// thread; the launch itself runs on a blocking thread. Every launch setting
+// is its own optional argument so the frontend only passes what it changes
 #[tauri::command]
-pub fn start_backend_server(app_handle: tauri::AppHandle) -> Result<String, String> {
-    start_backend_server_internal(app_handle)
+#[allow(clippy::too_many_arguments)]
+pub async fn start_backend_server(
+    app_handle: tauri::AppHandle,
+    id: Option<String>,
+    working_dir: Option<String>,
+    script: Option<String>,
+    port: Option<u16>,
+    env: Option<HashMap<String, String>>,
+    node_path: Option<String>,
+    npm_path: Option<String>,
+    ready_timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    let options = LaunchOptions {
+        working_dir,
+        script,
+        port,
+        env: env.unwrap_or_default(),
+        node_path,
+        npm_path,
+        ready_timeout_ms,
+    };
+    
+    let id = instance_id(id);
+    
+    tauri::async_runtime::spawn_blocking(move || start_backend_server_internal(app_handle, &id, &options))
+        .await
+        .map_err(|e| format!("Failed to start server: {}", e))?
 }
 
+// Returns whether the instance was actually running
+pub fn stop_backend_server_internal(app_handle: &tauri::AppHandle, id: &str) -> Result<bool, String> {
+    let state: tauri::State<ServerState> = app_handle.state();
+    stop_instance(&state, id)
+}
+
+// The instance is taken out of the map before terminate_child, which can
+// take several seconds, so other server commands aren't blocked behind it.
+// Removing it also ends the crash monitor without reporting a crash.
+fn stop_instance(state: &ServerState, id: &str) -> Result<bool, String> {
+    let removed = state.instances.lock().unwrap().remove(id);
+    let Some(mut instance) = removed else {
+        return Ok(false);
+    };
+    
+    if let Err(e) = terminate_child(&mut instance.process) {
+        // Keep tracking a process that refused to die so it isn't lost,
+        // unless a new launch has taken the id in the meantime
+        state
+            .instances
+            .lock()
+            .unwrap()
+            .entry(id.to_string())
+            .or_insert(instance);
+        return Err(e);
+    }
+    
+    Ok(true)
+}
+
+// Returns the ids that were stopped and the errors for any that weren't
+pub fn stop_all_backend_servers_internal(app_handle: &tauri::AppHandle) -> (Vec<String>, Vec<String>) {
+    let state: tauri::State<ServerState> = app_handle.state();
+    let ids: Vec<String> = state.instances.lock().unwrap().keys().cloned().collect();
+    
+    let mut stopped = Vec::new();
+    let mut errors = Vec::new();
+    
+    for id in ids {
+        match stop_backend_server_internal(app_handle, &id) {
+            Ok(true) => stopped.push(id),
+            Ok(false) => {}
+            Err(e) => errors.push(format!("Failed to stop server {}: {}", id, e)),
+        }
+    }
+    
+    (stopped, errors)
+}
+
+// Async like start and restart, since a server that ignores the graceful
+// stop is only killed after a timeout
 #[tauri::command]
-pub fn stop_backend_server(app_handle: tauri::AppHandle) -> Result<String, String> {
+pub async fn stop_backend_server(app_handle: tauri::AppHandle, id: Option<String>) -> Result<String, String> {
+    let id = instance_id(id);
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        if stop_backend_server_internal(&app_handle, &id)? {
+            Ok(format!("Server {} stopped successfully", id))
+        } else {
+            Ok(format!("Server {} was not running", id))
+        }
+    })
+    .await
+    .map_err(|e| format!("Failed to stop server: {}", e))?
+}
+
+// Watches for the server exiting on its own. A deliberate stop removes the
+// instance first, so that ends the monitor without reporting a crash
+fn monitor_server_process(app_handle: tauri::AppHandle, id: String, generation: u64) {
+    loop {
+        thread::sleep(CRASH_MONITOR_INTERVAL);
+        
+        let state: tauri::State<ServerState> = app_handle.state();
+        let mut instances = state.instances.lock().unwrap();
+        
+        let status = match instances.get_mut(&id) {
+            Some(instance) if instance.generation == generation => match instance.process.try_wait() {
+                Ok(Some(status)) => status,
+                Ok(None) => continue,
+                Err(e) => {
+                    eprintln!("❌ Failed to check server {} process: {}", id, e);
+                    return;
+                }
+            },
+            _ => return,
+        };
+        
+        instances.remove(&id);
+        drop(instances);
+        
+        let restarting = state.auto_restart.load(Ordering::SeqCst);
+        eprintln!("❌ Backend server {} exited unexpectedly ({})", id, status);
+        let _ = app_handle.emit(
+            "server-crashed",
+            ServerCrashEvent {
+                instance: id.clone(),
+                exit_code: status.code(),
+                restarting,
+            },
+        );
+        
+        if restarting {
+            let options = state.last_options.lock().unwrap().get(&id).cloned().unwrap_or_default();
+            if let Err(e) = start_backend_server_internal(app_handle.clone(), &id, &options) {
+                eprintln!("❌ Failed to restart crashed server {}: {}", id, e);
+            }
+        }
+        return;
+    }
+}
+
+#[tauri::command]
+pub fn set_server_auto_restart(app_handle: tauri::AppHandle, enabled: bool) -> Result<String, String> {
     let state: tauri::State<ServerState> = app_handle.state();
-    let mut process = state.process.lock().unwrap();
+    state.auto_restart.store(enabled, Ordering::SeqCst);
     
-    if let Some(child) = process.take() {
-        #[cfg(target_os = "windows")]
-        {
-            // On Windows, kill the process tree
-            let _ = Command::new("taskkill")
-                .args(["/F", "/T", "/PID", &child.id().to_string()])
-                .spawn();
+    Ok(format!(
+        "Server auto-restart {}",
+        if enabled { "enabled" } else { "disabled" }
+    ))
+}
+
+// Relaunches with the options of the last start, once the old process has
+// really exited and released its port
+#[tauri::command]
+pub async fn restart_backend_server(app_handle: tauri::AppHandle, id: Option<String>) -> Result<String, String> {
+    let id = instance_id(id);
+    
+    tauri::async_runtime::spawn_blocking(move || {
+        let state: tauri::State<ServerState> = app_handle.state();
+        let options = state.last_options.lock().unwrap().get(&id).cloned().unwrap_or_default();
+        
+        stop_backend_server_internal(&app_handle, &id)?;
+        start_backend_server_internal(app_handle.clone(), &id, &options)
+    })
+    .await
+    .map_err(|e| format!("Failed to restart server: {}", e))?
+}
+
+// "starting" covers a live process that isn't answering its health check
+// yet, so the frontend doesn't fire requests before the port is bound.
+// Blocks for as long as the health probe takes.
+pub fn server_status_internal(app_handle: &tauri::AppHandle, id: &str) -> ServerStatusInfo {
+    let state: tauri::State<ServerState> = app_handle.state();
+    
+    let Some(live) = live_instance(&state, id) else {
+        return ServerStatusInfo {
+            status: "stopped".to_string(),
+            pid: None,
+            uptime_secs: None,
+            port: None,
+        };
+    };
+    
+    let status = if server_healthy(&state, live.port) { "running" } else { "starting" };
+    
+    ServerStatusInfo {
+        status: status.to_string(),
+        pid: Some(live.pid),
+        uptime_secs: Some(live.started_at.elapsed().as_secs()),
+        port: Some(live.port),
+    }
+}
+
+// Async because the frontend polls this, and a server that accepts the
+// connection but never answers holds the probe for its full timeout
+#[tauri::command]
+pub async fn get_server_status(app_handle: tauri::AppHandle, id: Option<String>) -> Result<ServerStatusInfo, String> {
+    let id = instance_id(id);
+    
+    tauri::async_runtime::spawn_blocking(move || server_status_internal(&app_handle, &id))
+        .await
+        .map_err(|e| format!("Failed to get server status: {}", e))
+}
+
+// Base URL for the frontend's API requests, so the host and port only live
+// on this side
+#[tauri::command]
+pub fn get_server_url(app_handle: tauri::AppHandle, id: Option<String>) -> Result<String, String> {
+    let id = instance_id(id);
+    let state: tauri::State<ServerState> = app_handle.state();
+    
+    let live = live_instance(&state, &id)
+        .ok_or_else(|| format!("Server {} is not running", id))?;
+    let host = state.host.lock().unwrap().clone();
+    
+    Ok(format!("http://{}:{}", host, live.port))
+}
+
+// Accepts http://host[:port][/path]; the path defaults to / and a missing
+// port means the server's own port
+fn parse_health_url(url: &str) -> Result<HealthEndpoint, String> {
+    let rest = url
+        .strip_prefix("http://")
+        .ok_or_else(|| format!("Health URL must start with http://: {}", url))?;
+    
+    let (authority, path) = match rest.find('/') {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "/"),
+    };
+    
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => {
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| format!("Invalid port in health URL: {}", url))?;
+            (host, Some(port))
         }
+        None => (authority, None),
+    };
+    
+    if host.is_empty() {
+        return Err(format!("Missing host in health URL: {}", url));
+    }
+    
+    Ok(HealthEndpoint {
+        host: Some(host.to_string()),
+        port,
+        path: path.to_string(),
+    })
+}
+
+#[tauri::command]
+pub fn set_server_health_endpoint(app_handle: tauri::AppHandle, url: String) -> Result<(), String> {
+    let endpoint = parse_health_url(&url)?;
+    
+    let state: tauri::State<ServerState> = app_handle.state();
+    *state.health_endpoint.lock().unwrap() = endpoint;
+    
+    Ok(())
+}
+
+// Simpler than set_server_health_endpoint when only the host or the
+// readiness path differs; anything left out keeps its current value
+#[tauri::command]
+pub fn configure_server_endpoint(
+    app_handle: tauri::AppHandle,
+    host: Option<String>,
+    health_path: Option<String>,
+) -> Result<(), String> {
+    let state: tauri::State<ServerState> = app_handle.state();
+    let host_given = host.is_some();
+    let host = host
+        .map(|host| host.trim().to_string())
+        .unwrap_or_else(|| state.host.lock().unwrap().clone());
+    let path = health_path.unwrap_or_else(|| state.health_endpoint.lock().unwrap().path.clone());
+    
+    // Checked by the same parser as set_server_health_endpoint. A path
+    // without its leading / would run into the host, and a host with a port
+    // would split off, so anything that doesn't round-trip is rejected.
+    let url = format!("http://{}{}", host, path);
+    let parsed = parse_health_url(&url)?;
+    if parsed.host.as_deref() != Some(host.as_str()) || parsed.port.is_some() || parsed.path != path {
+        return Err(format!("Invalid server host or health path: {}", url));
+    }
+    
+    let mut endpoint = state.health_endpoint.lock().unwrap();
+    if host_given {
+        // Drop a host set by set_server_health_endpoint so the health probe
+        // follows the server to its new host
+        endpoint.host = None;
+        *state.host.lock().unwrap() = host;
+    }
+    endpoint.path = path;
+    
+    Ok(())
+}
+
+#[tauri::command]
+pub fn load_server_log(
+    file_path: String,
+    from_line: usize,
+    count: usize,
+) -> Result<Vec<String>, String> {
+    let file = File::open(&file_path)
+        .map_err(|e| format!("Failed to open log file {}: {}", file_path, e))?;
+    
+    // Split on raw bytes so a stray non-UTF-8 byte from the server doesn't
+    // make the whole page unreadable
+    BufReader::new(file)
+        .split(b'\n')
+        .skip(from_line)
+        .take(count)
+        .map(|line| {
+            line.map(|bytes| String::from_utf8_lossy(&bytes).trim_end_matches('\r').to_string())
+                .map_err(|e| format!("Failed to read log file {}: {}", file_path, e))
+        })
+        .collect()
+}
+
+// Oldest first, up to the last SERVER_LOG_CAPACITY lines, or only the last
+// `lines` of them when given. Empty rather than an error before any server
+// has run.
+#[tauri::command]
+pub fn get_server_logs(app_handle: tauri::AppHandle, lines: Option<usize>) -> Vec<ServerLogLine> {
+    let state: tauri::State<ServerState> = app_handle.state();
+    let logs = state.logs.lock().unwrap();
+    let skip = logs.len().saturating_sub(lines.unwrap_or(logs.len()));
+    logs.iter().skip(skip).cloned().collect()
+}
+
+#[tauri::command]
+pub fn dump_server_logs(app_handle: tauri::AppHandle, file_path: String) -> Result<usize, String> {
+    let state: tauri::State<ServerState> = app_handle.state();
+    // Copy out first so the capture threads aren't blocked on disk I/O
+    let lines: Vec<ServerLogLine> = state.logs.lock().unwrap().iter().cloned().collect();
+    
+    let file = File::create(&file_path)
+        .map_err(|e| format!("Failed to create {}: {}", file_path, e))?;
+    let mut writer = BufWriter::new(file);
+    
+    for entry in &lines {
+        writeln!(writer, "[{}:{}] {}", entry.instance, entry.stream, entry.line)
+            .map_err(|e| format!("Failed to write {}: {}", file_path, e))?;
+    }
+    writer.flush()
+        .map_err(|e| format!("Failed to write {}: {}", file_path, e))?;
+    
+    Ok(lines.len())
+}
+
+// Plain HTTP/1.1 GET so the probe doesn't need an HTTP client dependency;
+// any 2xx status counts as healthy
+fn probe_health(host: &str, port: u16, path: &str) -> bool {
+    let Ok(addrs) = (host, port).to_socket_addrs() else {
+        return false;
+    };
+    
+    for addr in addrs {
+        let Ok(mut stream) = TcpStream::connect_timeout(&addr, HEALTH_TIMEOUT) else {
+            continue;
+        };
+        let _ = stream.set_read_timeout(Some(HEALTH_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(HEALTH_TIMEOUT));
         
-        #[cfg(not(target_os = "windows"))]
-        {
-            child.kill().map_err(|e| format!("Failed to stop server: {}", e))?;
+        let request = format!(
+            "GET {} HTTP/1.1\r\nHost: {}:{}\r\nConnection: close\r\n\r\n",
+            path, host, port
+        );
+        if stream.write_all(request.as_bytes()).is_err() {
+            continue;
         }
         
-        Ok("Server stopped successfully".to_string())
-    } else {
-        Ok("Server was not running".to_string())
+        let mut status_line = String::new();
+        if BufReader::new(stream).read_line(&mut status_line).is_err() {
+            continue;
+        }
+        
+        return status_line
+            .split_whitespace()
+            .nth(1)
+            .and_then(|code| code.parse::<u16>().ok())
+            .is_some_and(|code| (200..300).contains(&code));
+    }
+    
+    false
+}
+
+fn server_healthy(state: &ServerState, server_port: u16) -> bool {
+    let endpoint = state.health_endpoint.lock().unwrap().clone();
+    let host = endpoint
+        .host
+        .unwrap_or_else(|| state.host.lock().unwrap().clone());
+    let port = endpoint.port.unwrap_or(server_port);
+    probe_health(&host, port, &endpoint.path)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoStartSettings {
+    pub enabled: bool,
+    // Gives Tauri time to finish initialising before the spawn
+    pub delay_ms: u64,
+}
+
+impl Default for AutoStartSettings {
+    fn default() -> Self {
+        AutoStartSettings {
+            enabled: true,
+            delay_ms: DEFAULT_AUTO_START_DELAY_MS,
+        }
     }
 }
 
+fn auto_start_settings_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let config_dir = app_handle.path()
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve config directory: {}", e))?;
+    Ok(config_dir.join(AUTO_START_SETTINGS_FILE))
+}
+
+// A missing or unreadable file falls back to the defaults so a bad edit
+// can't stop the app from launching
+pub fn load_auto_start_settings(app_handle: &tauri::AppHandle) -> AutoStartSettings {
+    let Ok(path) = auto_start_settings_path(app_handle) else {
+        return AutoStartSettings::default();
+    };
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return AutoStartSettings::default();
+    };
+    
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        eprintln!("⚠️ Ignoring invalid auto-start settings in {:?}: {}", path, e);
+        AutoStartSettings::default()
+    })
+}
+
+#[tauri::command]
+pub fn get_server_auto_start(app_handle: tauri::AppHandle) -> AutoStartSettings {
+    load_auto_start_settings(&app_handle)
+}
+
+// Takes effect the next time the app launches. Leaving out `delay_ms` keeps
+// the saved delay, so the frontend can flip auto-start on and off without
+// knowing it.
 #[tauri::command]
-pub fn get_server_status(app_handle: tauri::AppHandle) -> Result<String, String> {
+pub fn set_server_auto_start(
+    app_handle: tauri::AppHandle,
+    enabled: bool,
+    delay_ms: Option<u64>,
+) -> Result<AutoStartSettings, String> {
+    let settings = AutoStartSettings {
+        enabled,
+        delay_ms: delay_ms.unwrap_or_else(|| load_auto_start_settings(&app_handle).delay_ms),
+    };
+    
+    let path = auto_start_settings_path(&app_handle)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {:?}: {}", dir, e))?;
+    }
+    
+    let json = serde_json::to_string_pretty(&settings)
+        .map_err(|e| format!("Failed to serialize auto-start settings: {}", e))?;
+    std::fs::write(&path, json)
+        .map_err(|e| format!("Failed to write {:?}: {}", path, e))?;
+    
+    Ok(settings)
+}
+
+// Copied out of a ServerInstance so callers don't hold the instances lock
+#[derive(Debug, Clone, Copy)]
+struct LiveInstance {
+    generation: u64,
+    pid: u32,
+    port: u16,
+    started_at: Instant,
+}
+
+// None unless the instance exists and its process is still alive
+fn live_instance(state: &ServerState, id: &str) -> Option<LiveInstance> {
+    let mut instances = state.instances.lock().unwrap();
+    let instance = instances.get_mut(id)?;
+    
+    match instance.process.try_wait() {
+        Ok(None) => Some(LiveInstance {
+            generation: instance.generation,
+            pid: instance.process.id(),
+            port: instance.port,
+            started_at: instance.started_at,
+        }),
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct SupervisedInstance {
+    // The process the counts below belong to
+    generation: u64,
+    consecutive_failures: u32,
+    // When the current run of failures began; a run older than the
+    // supervisor's window starts over
+    first_failure: Option<Instant>,
+    // Failures only count once the server has answered at least once, so a
+    // slow startup isn't mistaken for a hang
+    seen_healthy: bool,
+    restarted: bool,
+}
+
+fn run_supervisor(
+    app_handle: tauri::AppHandle,
+    stop: Arc<AtomicBool>,
+    failure_threshold: u32,
+    interval: Duration,
+    window: Duration,
+) {
+    let mut supervised: HashMap<String, SupervisedInstance> = HashMap::new();
+    
+    while !stop.load(Ordering::SeqCst) {
+        // Sleep in short slices so disabling the supervisor doesn't block for
+        // a whole interval
+        let mut waited = Duration::ZERO;
+        while waited < interval && !stop.load(Ordering::SeqCst) {
+            let slice = SUPERVISOR_STOP_POLL.min(interval - waited);
+            thread::sleep(slice);
+            waited += slice;
+        }
+        
+        let state: tauri::State<ServerState> = app_handle.state();
+        let ids: Vec<String> = state.instances.lock().unwrap().keys().cloned().collect();
+        supervised.retain(|id, _| ids.contains(id));
+        
+        for id in ids {
+            if stop.load(Ordering::SeqCst) {
+                break;
+            }
+            
+            let Some(live) = live_instance(&state, &id) else {
+                if let Some(health) = supervised.get_mut(&id) {
+                    health.consecutive_failures = 0;
+                    health.first_failure = None;
+                }
+                continue;
+            };
+            let health = supervised.entry(id.clone()).or_default();
+            
+            // Restarted by someone else since the last check: the new
+            // process gets the same startup grace as the first, so the
+            // supervisor doesn't restart it again while it comes up
+            if health.generation != live.generation {
+                *health = SupervisedInstance {
+                    generation: live.generation,
+                    restarted: health.restarted,
+                    ..SupervisedInstance::default()
+                };
+            }
+            
+            if server_healthy(&state, live.port) {
+                if health.restarted {
+                    let _ = app_handle.emit(
+                        "server-recovered",
+                        ServerHealthEvent {
+                            instance: id.clone(),
+                            consecutive_failures: health.consecutive_failures,
+                        },
+                    );
+                    health.restarted = false;
+                }
+                health.seen_healthy = true;
+                health.consecutive_failures = 0;
+                health.first_failure = None;
+                continue;
+            }
+            
+            if !health.seen_healthy {
+                continue;
+            }
+            
+            // Failures spread out wider than the window aren't a hang, so
+            // an old run is dropped and this failure starts a new one
+            let now = Instant::now();
+            match health.first_failure {
+                Some(first) if now.duration_since(first) <= window => {}
+                _ => {
+                    health.first_failure = Some(now);
+                    health.consecutive_failures = 0;
+                }
+            }
+            health.consecutive_failures += 1;
+            if health.consecutive_failures < failure_threshold {
+                continue;
+            }
+            
+            let _ = app_handle.emit(
+                "server-unhealthy",
+                ServerHealthEvent {
+                    instance: id.clone(),
+                    consecutive_failures: health.consecutive_failures,
+                },
+            );
+            
+            let options = state.last_options.lock().unwrap().get(&id).cloned().unwrap_or_default();
+            let result = stop_backend_server_internal(&app_handle, &id)
+                .and_then(|_| start_backend_server_internal(app_handle.clone(), &id, &options));
+            
+            match result {
+                Ok(_) => health.restarted = true,
+                Err(e) => eprintln!("❌ Supervisor failed to restart server {}: {}", id, e),
+            }
+            health.consecutive_failures = 0;
+            health.first_failure = None;
+            health.seen_healthy = false;
+        }
+    }
+}
+
+pub fn stop_server_supervisor(app_handle: &tauri::AppHandle) {
     let state: tauri::State<ServerState> = app_handle.state();
-    let process = state.process.lock().unwrap();
+    let supervisor = state.supervisor.lock().unwrap().take();
     
-    if process.is_some() {
-        Ok("running".to_string())
-    } else {
-        Ok("stopped".to_string())
+    if let Some(supervisor) = supervisor {
+        supervisor.stop.store(true, Ordering::SeqCst);
+        let _ = supervisor.thread.join();
+    }
+}
+
+#[tauri::command]
+pub fn configure_server_supervisor(
+    app_handle: tauri::AppHandle,
+    enabled: bool,
+    failure_threshold: Option<u32>,
+    interval_ms: Option<u64>,
+    window_ms: Option<u64>,
+) -> Result<String, String> {
+    stop_server_supervisor(&app_handle);
+    
+    if !enabled {
+        return Ok("Server supervisor disabled".to_string());
+    }
+    
+    let failure_threshold = failure_threshold.unwrap_or(DEFAULT_SUPERVISOR_FAILURE_THRESHOLD);
+    if failure_threshold == 0 {
+        return Err("Failure threshold must be at least 1".to_string());
     }
-}
\ No newline at end of file
+    let interval = Duration::from_millis(interval_ms.unwrap_or(DEFAULT_SUPERVISOR_INTERVAL_MS).max(100));
+    let window = Duration::from_millis(window_ms.unwrap_or(DEFAULT_SUPERVISOR_WINDOW_MS));
+    if window < interval.saturating_mul(failure_threshold - 1) {
+        return Err(format!(
+            "Failure window must be at least {} ms to fit {} health checks",
+            interval.saturating_mul(failure_threshold - 1).as_millis(),
+            failure_threshold
+        ));
+    }
+    
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread = {
+        let app_handle = app_handle.clone();
+        let stop = stop.clone();
+        thread::spawn(move || run_supervisor(app_handle, stop, failure_threshold, interval, window))
+    };
+    
+    let state: tauri::State<ServerState> = app_handle.state();
+    *state.supervisor.lock().unwrap() = Some(Supervisor { stop, thread });
+    
+    Ok(format!(
+        "Server supervisor enabled (restart after {} failed health checks within {} ms)",
+        failure_threshold,
+        window.as_millis()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn long_running_process() -> Child {
+        #[cfg(target_os = "windows")]
+        let mut command = {
+            let mut command = Command::new("ping");
+            command.args(["-n", "30", "127.0.0.1"]);
+            command
+        };
+        #[cfg(not(target_os = "windows"))]
+        let mut command = {
+            let mut command = Command::new("sleep");
+            command.arg("30");
+            command
+        };
+        
+        command
+            .stdout(Stdio::null())
+            .spawn()
+            .expect("failed to start test process")
+    }
+
+    #[test]
+    fn stop_reaps_the_process_and_clears_state() {
+        let state = ServerState::new();
+        let process = long_running_process();
+        #[cfg(not(target_os = "windows"))]
+        let pid = process.id().to_string();
+        
+        state.instances.lock().unwrap().insert(
+            DEFAULT_INSTANCE_ID.to_string(),
+            ServerInstance {
+                process,
+                launch_command: "test process".to_string(),
+                port: SERVER_PORT,
+                started_at: Instant::now(),
+                generation: 0,
+            },
+        );
+        
+        assert_eq!(stop_instance(&state, DEFAULT_INSTANCE_ID), Ok(true));
+        assert!(state.instances.lock().unwrap().is_empty());
+        
+        // An unreaped zombie still answers kill -0
+        #[cfg(not(target_os = "windows"))]
+        {
+            let status = Command::new("kill")
+                .args(["-0", &pid])
+                .stderr(Stdio::null())
+                .status()
+                .unwrap();
+            assert!(!status.success());
+        }
+        
+        assert_eq!(stop_instance(&state, DEFAULT_INSTANCE_ID), Ok(false));
+    }
+}
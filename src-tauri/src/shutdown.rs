@@ -0,0 +1,53 @@
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State};
+
+use crate::serial::SerialManager;
+use crate::server;
+
+#[derive(Debug, Serialize)]
+pub struct ShutdownReport {
+    pub server_stopped: bool,
+    pub ports_closed: Vec<String>,
+    pub threads_remaining: usize,
+    pub errors: Vec<String>,
+}
+
+// Tear everything down in dependency order: the backend first, then the
+// serial ports. Errors are collected rather than aborting so one failure
+// doesn't leave the remaining subsystems running.
+pub fn shutdown_all_internal(app_handle: &AppHandle) -> ShutdownReport {
+    let mut report = ShutdownReport {
+        server_stopped: false,
+        ports_closed: Vec::new(),
+        threads_remaining: 0,
+        errors: Vec::new(),
+    };
+    
+    // The supervisor would otherwise see the server go away and restart it
+    server::stop_server_supervisor(app_handle);
+    
+    let (stopped, errors) = server::stop_all_backend_servers_internal(app_handle);
+    report.server_stopped = !stopped.is_empty();
+    report.errors.extend(errors);
+    
+    let manager: State<SerialManager> = app_handle.state();
+    manager.stop_port_watch();
+    manager.stop_bridges();
+    
+    match manager.close_all() {
+        Ok((closed, errors)) => {
+            report.ports_closed = closed;
+            report.errors.extend(errors);
+        }
+        Err(e) => report.errors.push(format!("Failed to close serial ports: {}", e)),
+    }
+    
+    report.threads_remaining = manager.active_thread_count();
+    
+    report
+}
+
+#[tauri::command]
+pub fn shutdown_all(app_handle: AppHandle) -> Result<ShutdownReport, String> {
+    Ok(shutdown_all_internal(&app_handle))
+}
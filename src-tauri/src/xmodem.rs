@@ -0,0 +1,357 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::time::{Duration, Instant};
+
+const SOH: u8 = 0x01;
+const EOT: u8 = 0x04;
+const ACK: u8 = 0x06;
+const NAK: u8 = 0x15;
+const CAN: u8 = 0x18;
+// Sent by a receiver asking for CRC-16 instead of the 8-bit checksum
+const CRC_REQUEST: u8 = b'C';
+// Pads the last block out to BLOCK_SIZE
+const SUB: u8 = 0x1a;
+
+const BLOCK_SIZE: usize = 128;
+const MAX_RETRIES: u32 = 10;
+// Bootloaders often sit in a reset or wait for a button press before they
+// start asking for data
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(60);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub enum XmodemError {
+    Io(io::Error),
+    // The receiver sent CAN CAN
+    Cancelled,
+    // The sender's own cancel check fired
+    Aborted,
+    Protocol(String),
+}
+
+impl fmt::Display for XmodemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XmodemError::Io(e) => write!(f, "{}", e),
+            XmodemError::Cancelled => f.write_str("Transfer cancelled by the receiver"),
+            XmodemError::Aborted => f.write_str("Transfer cancelled"),
+            XmodemError::Protocol(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<io::Error> for XmodemError {
+    fn from(e: io::Error) -> Self {
+        XmodemError::Io(e)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Checksum {
+    Sum,
+    Crc16,
+}
+
+// CRC-16/XMODEM: polynomial 0x1021, initial value 0, no reflection
+fn crc16(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, &byte| {
+        let mut crc = crc ^ ((byte as u16) << 8);
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+        crc
+    })
+}
+
+fn sum(data: &[u8]) -> u8 {
+    data.iter().fold(0u8, |acc, &byte| acc.wrapping_add(byte))
+}
+
+// The port with one byte of lookahead, so a byte read while checking for a
+// second CAN can be handed back to the caller instead of being lost
+struct Link<'a, P, C> {
+    port: &'a mut P,
+    pending: Option<u8>,
+    // Polled between reads so the sender can abandon a transfer
+    cancelled: C,
+}
+
+impl<P: Read + Write, C: FnMut() -> bool> Link<'_, P, C> {
+    // Waits for one byte until the deadline. Reads that hit the port's own
+    // timeout are retried, so the deadline rather than the port decides when
+    // to give up.
+    fn read_byte(&mut self, deadline: Instant) -> Result<Option<u8>, XmodemError> {
+        if let Some(byte) = self.pending.take() {
+            return Ok(Some(byte));
+        }
+        
+        let mut byte = [0u8; 1];
+        while Instant::now() < deadline {
+            if (self.cancelled)() {
+                return Err(XmodemError::Aborted);
+            }
+            match self.port.read(&mut byte) {
+                Ok(1) => return Ok(Some(byte[0])),
+                Ok(_) => {}
+                Err(ref e) if e.kind() == io::ErrorKind::TimedOut => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        
+        Ok(None)
+    }
+
+    // A single CAN may be line noise; the protocol aborts on two in a row.
+    // Whatever follows a lone CAN is kept for the next read_byte.
+    fn is_cancel(&mut self, byte: u8) -> Result<bool, XmodemError> {
+        if byte != CAN {
+            return Ok(false);
+        }
+        
+        match self.read_byte(Instant::now() + Duration::from_secs(1))? {
+            Some(CAN) => Ok(true),
+            next => {
+                self.pending = next;
+                Ok(false)
+            }
+        }
+    }
+
+    fn wait_for_handshake(&mut self) -> Result<Checksum, XmodemError> {
+        let deadline = Instant::now() + HANDSHAKE_TIMEOUT;
+        
+        loop {
+            match self.read_byte(deadline)? {
+                Some(NAK) => return Ok(Checksum::Sum),
+                Some(CRC_REQUEST) => return Ok(Checksum::Crc16),
+                Some(byte) if self.is_cancel(byte)? => return Err(XmodemError::Cancelled),
+                // Boot banners and other chatter before the receiver is ready
+                Some(_) => {}
+                None => {
+                    return Err(XmodemError::Protocol(
+                        "Receiver did not start the transfer (no NAK or 'C' received)".to_string(),
+                    ))
+                }
+            }
+        }
+    }
+
+    // Sends `packet` until the receiver ACKs it, giving up after MAX_RETRIES
+    fn send_until_acked(&mut self, packet: &[u8], what: &str) -> Result<(), XmodemError> {
+        for _ in 0..MAX_RETRIES {
+            self.port.write_all(packet)?;
+            self.port.flush()?;
+            
+            let deadline = Instant::now() + RESPONSE_TIMEOUT;
+            loop {
+                match self.read_byte(deadline)? {
+                    Some(ACK) => return Ok(()),
+                    Some(byte) if self.is_cancel(byte)? => return Err(XmodemError::Cancelled),
+                    // NAK, a timeout or garbage all mean send it again
+                    Some(NAK) | None => break,
+                    Some(_) => {}
+                }
+            }
+        }
+        
+        Err(XmodemError::Protocol(format!(
+            "{} was not acknowledged after {} attempts",
+            what, MAX_RETRIES
+        )))
+    }
+}
+
+// Block numbers start at 1 and wrap after 255
+fn block_number(index: usize) -> u8 {
+    (index + 1) as u8
+}
+
+fn build_packet(block: u8, chunk: &[u8], checksum: Checksum) -> Vec<u8> {
+    let mut data = chunk.to_vec();
+    data.resize(BLOCK_SIZE, SUB);
+    
+    let mut packet = Vec::with_capacity(BLOCK_SIZE + 5);
+    packet.extend_from_slice(&[SOH, block, 255 - block]);
+    packet.extend_from_slice(&data);
+    match checksum {
+        Checksum::Sum => packet.push(sum(&data)),
+        Checksum::Crc16 => packet.extend_from_slice(&crc16(&data).to_be_bytes()),
+    }
+    packet
+}
+
+// Sends `data` to a receiver that has been told to expect an XMODEM upload.
+// Uses CRC-16 or the 8-bit checksum, whichever the receiver asks for, and
+// calls `on_block` with the number of bytes acknowledged so far. The
+// transfer is abandoned with Aborted as soon as `cancelled` returns true.
+pub fn send<P, F, C>(
+    port: &mut P,
+    data: &[u8],
+    mut on_block: F,
+    cancelled: C,
+) -> Result<(), XmodemError>
+where
+    P: Read + Write,
+    F: FnMut(usize),
+    C: FnMut() -> bool,
+{
+    let mut link = Link {
+        port,
+        pending: None,
+        cancelled,
+    };
+    
+    let result = (|| -> Result<(), XmodemError> {
+        let checksum = link.wait_for_handshake()?;
+        
+        for (index, chunk) in data.chunks(BLOCK_SIZE).enumerate() {
+            let packet = build_packet(block_number(index), chunk, checksum);
+            link.send_until_acked(&packet, &format!("Block {}", index + 1))?;
+            on_block(index * BLOCK_SIZE + chunk.len());
+        }
+        
+        link.send_until_acked(&[EOT], "End of transmission")
+    })();
+    
+    // Tell the receiver to stop waiting rather than leave it mid-transfer
+    if matches!(result, Err(XmodemError::Protocol(_)) | Err(XmodemError::Aborted)) {
+        let _ = link.port.write_all(&[CAN, CAN]);
+        let _ = link.port.flush();
+    }
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    // Plays back a scripted receiver. Running out of script reads as a port
+    // timeout, which only ends once the protocol's own deadline passes.
+    struct MockReceiver {
+        replies: VecDeque<u8>,
+        written: Vec<u8>,
+    }
+
+    impl MockReceiver {
+        fn new(replies: &[u8]) -> Self {
+            MockReceiver {
+                replies: replies.iter().copied().collect(),
+                written: Vec::new(),
+            }
+        }
+    }
+
+    impl Read for MockReceiver {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.replies.pop_front() {
+                Some(byte) => {
+                    buf[0] = byte;
+                    Ok(1)
+                }
+                None => Err(io::Error::new(io::ErrorKind::TimedOut, "no reply")),
+            }
+        }
+    }
+
+    impl Write for MockReceiver {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.written.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn send_all(receiver: &mut MockReceiver, data: &[u8]) -> Result<(), XmodemError> {
+        send(receiver, data, |_| {}, || false)
+    }
+
+    #[test]
+    fn crc16_matches_the_xmodem_check_value() {
+        assert_eq!(crc16(b"123456789"), 0x31C3);
+    }
+
+    #[test]
+    fn block_numbers_start_at_one_and_wrap() {
+        assert_eq!(block_number(0), 1);
+        assert_eq!(block_number(254), 255);
+        assert_eq!(block_number(255), 0);
+        
+        assert_eq!(build_packet(1, b"x", Checksum::Sum)[..3], [SOH, 1, 254]);
+        assert_eq!(build_packet(0, b"x", Checksum::Sum)[..3], [SOH, 0, 255]);
+    }
+
+    #[test]
+    fn last_block_is_padded_with_sub() {
+        let packet = build_packet(1, b"abc", Checksum::Crc16);
+        assert_eq!(packet.len(), BLOCK_SIZE + 5);
+        
+        let data = &packet[3..3 + BLOCK_SIZE];
+        assert_eq!(&data[..3], b"abc");
+        assert!(data[3..].iter().all(|&byte| byte == SUB));
+        assert_eq!(packet[3 + BLOCK_SIZE..], crc16(data).to_be_bytes());
+        
+        let packet = build_packet(1, b"abc", Checksum::Sum);
+        assert_eq!(packet.len(), BLOCK_SIZE + 4);
+        assert_eq!(packet[3 + BLOCK_SIZE], sum(&packet[3..3 + BLOCK_SIZE]));
+    }
+
+    #[test]
+    fn nak_resends_the_block() {
+        let mut receiver = MockReceiver::new(&[CRC_REQUEST, NAK, ACK, ACK]);
+        send_all(&mut receiver, b"hello").unwrap();
+        
+        let packet = build_packet(1, b"hello", Checksum::Crc16);
+        let mut expected = [packet.clone(), packet].concat();
+        expected.push(EOT);
+        assert_eq!(receiver.written, expected);
+    }
+
+    #[test]
+    fn gives_up_after_max_retries_and_cancels_the_receiver() {
+        let mut replies = vec![NAK];
+        replies.extend(std::iter::repeat(NAK).take(MAX_RETRIES as usize));
+        let mut receiver = MockReceiver::new(&replies);
+        
+        let result = send_all(&mut receiver, b"hello");
+        assert!(matches!(result, Err(XmodemError::Protocol(_))));
+        
+        let packet = build_packet(1, b"hello", Checksum::Sum);
+        let mut expected = packet.repeat(MAX_RETRIES as usize);
+        expected.extend_from_slice(&[CAN, CAN]);
+        assert_eq!(receiver.written, expected);
+    }
+
+    #[test]
+    fn eot_is_resent_until_acknowledged() {
+        let mut receiver = MockReceiver::new(&[NAK, ACK, NAK, ACK]);
+        send_all(&mut receiver, b"hello").unwrap();
+        
+        let packet = build_packet(1, b"hello", Checksum::Sum);
+        assert!(receiver.written.starts_with(&packet));
+        assert_eq!(receiver.written[packet.len()..], [EOT, EOT]);
+    }
+
+    #[test]
+    fn ack_after_a_lone_can_is_not_lost() {
+        let mut receiver = MockReceiver::new(&[CRC_REQUEST, CAN, ACK, ACK]);
+        send_all(&mut receiver, b"hello").unwrap();
+        
+        let mut expected = build_packet(1, b"hello", Checksum::Crc16);
+        expected.push(EOT);
+        assert_eq!(receiver.written, expected);
+    }
+
+    #[test]
+    fn cancel_check_aborts_and_cancels_the_receiver() {
+        let mut receiver = MockReceiver::new(&[CRC_REQUEST, ACK, ACK]);
+        let result = send(&mut receiver, b"hello", |_| {}, || true);
+        
+        assert!(matches!(result, Err(XmodemError::Aborted)));
+        assert_eq!(receiver.written, [CAN, CAN]);
+    }
+}